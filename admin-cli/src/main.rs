@@ -0,0 +1,141 @@
+use argh::FromArgs;
+use chrono::Utc;
+use shared::{models::Item, repository::DynamoDbRepository};
+use uuid::Uuid;
+
+type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// Admin CLI for managing items against DynamoDB directly, without going
+/// through API Gateway
+#[derive(FromArgs)]
+struct AdminArgs {
+    #[argh(subcommand)]
+    command: Command,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+enum Command {
+    Ls(LsCommand),
+    Get(GetCommand),
+    Create(CreateCommand),
+    Delete(DeleteCommand),
+}
+
+/// List all items
+#[derive(FromArgs)]
+#[argh(subcommand, name = "ls")]
+struct LsCommand {}
+
+/// Get a single item by ID
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+struct GetCommand {
+    /// the item ID to look up
+    #[argh(option)]
+    id: String,
+}
+
+/// Create a new item
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+struct CreateCommand {
+    /// item name
+    #[argh(option)]
+    name: String,
+
+    /// optional item description
+    #[argh(option)]
+    description: Option<String>,
+
+    /// classification level: PUBLIC, INTERNAL, CONFIDENTIAL, or RESTRICTED (default: INTERNAL)
+    #[argh(option, default = "\"INTERNAL\".to_string()")]
+    classification: String,
+}
+
+/// Delete an item by ID
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+struct DeleteCommand {
+    /// the item ID to delete
+    #[argh(option)]
+    id: String,
+}
+
+/// Main entry point for the admin CLI
+///
+/// Loads AWS config from the environment (honoring `TABLE_NAME`), optionally
+/// reading a `.env` file for local testing, then dispatches to the requested
+/// subcommand against the shared `DynamoDbRepository`.
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    // Load a local .env file if present; it's fine if there isn't one
+    let _ = dotenvy::dotenv();
+
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .with_target(false)
+        .init();
+
+    let args: AdminArgs = argh::from_env();
+
+    let aws_config = aws_config::load_from_env().await;
+    let repo = DynamoDbRepository::new(&aws_config);
+
+    match args.command {
+        Command::Ls(_) => ls(&repo).await?,
+        Command::Get(cmd) => get(&repo, &cmd.id).await?,
+        Command::Create(cmd) => create(&repo, cmd).await?,
+        Command::Delete(cmd) => delete(&repo, &cmd.id).await?,
+    }
+
+    Ok(())
+}
+
+/// Lists every item in the table and prints them as pretty JSON
+async fn ls(repo: &DynamoDbRepository) -> Result<(), Error> {
+    let items = repo.list_items().await?;
+    println!("{}", serde_json::to_string_pretty(&items)?);
+
+    Ok(())
+}
+
+/// Looks up a single item by ID and prints it as pretty JSON
+///
+/// Prints a message to stderr and leaves the process exit code unchanged
+/// if no item with that ID exists.
+async fn get(repo: &DynamoDbRepository, id: &str) -> Result<(), Error> {
+    match repo.get_item(id).await? {
+        Some(item) => println!("{}", serde_json::to_string_pretty(&item)?),
+        None => eprintln!("No item found with ID: {}", id),
+    }
+
+    Ok(())
+}
+
+/// Creates a new item from the given command arguments and prints the
+/// resulting item as pretty JSON
+async fn create(repo: &DynamoDbRepository, cmd: CreateCommand) -> Result<(), Error> {
+    let item = Item {
+        id: Uuid::new_v4().to_string(),
+        name: cmd.name,
+        description: cmd.description,
+        created_at: Utc::now(),
+        classification: cmd.classification,
+        attachments: Vec::new(),
+    };
+
+    repo.create_item(&item).await?;
+
+    println!("{}", serde_json::to_string_pretty(&item)?);
+
+    Ok(())
+}
+
+/// Deletes the item with the given ID
+async fn delete(repo: &DynamoDbRepository, id: &str) -> Result<(), Error> {
+    repo.delete_item(id).await?;
+    println!("Deleted item with ID: {}", id);
+
+    Ok(())
+}
@@ -0,0 +1,324 @@
+use base64::Engine as _;
+use chrono::{DateTime, Duration, Utc};
+use lambda_http::{Body, Request};
+use ring::signature::{self, UnparsedPublicKey};
+use sha2::{Digest as Sha2Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use shared::AppError;
+
+/// Maximum allowed clock skew between a request's `Date` header and now,
+/// beyond which the request is rejected as a possible replay
+const MAX_CLOCK_SKEW: Duration = Duration::hours(12);
+
+/// The pseudo-headers a `Signature` header's `headers` field must cover.
+///
+/// `verify_date_header`/`verify_digest_header` only check the *current*
+/// `Date`/`Digest` against the *current* request; unless the signature
+/// itself is bound to `(request-target)`, `host`, `date`, and `digest`, a
+/// captured signature can be replayed against a different method/path/body
+/// while still verifying, since nothing cryptographically ties the
+/// signature to those values.
+const REQUIRED_SIGNED_HEADERS: [&str; 4] = ["(request-target)", "host", "date", "digest"];
+
+/// The authenticated principal behind a successfully verified request
+#[derive(Debug, Clone)]
+pub struct Principal {
+    /// The `keyId` the request signed with, used as the user ID recorded on
+    /// audit records
+    pub user_id: String,
+}
+
+/// The parsed `Signature` header fields
+struct ParsedSignature {
+    key_id: String,
+    headers: Vec<String>,
+    signature: Vec<u8>,
+}
+
+/// Resolves and caches RSA public keys (PKCS#1 DER) by `keyId`
+///
+/// Real key lookup (Secrets Manager, a peer's well-known endpoint, etc.)
+/// would live behind [`resolve`](Self::resolve); keys are loaded once from
+/// the `TRUSTED_PUBLIC_KEYS` environment variable (a JSON object mapping
+/// `keyId` to a base64-encoded DER public key) and cached for the lifetime
+/// of the Lambda execution environment.
+pub struct KeyResolver {
+    cache: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl KeyResolver {
+    pub fn from_env() -> Self {
+        let keys: HashMap<String, String> = std::env::var("TRUSTED_PUBLIC_KEYS")
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+
+        let cache = keys
+            .into_iter()
+            .filter_map(|(key_id, encoded)| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded)
+                    .ok()
+                    .map(|der| (key_id, der))
+            })
+            .collect();
+
+        Self { cache: RwLock::new(cache) }
+    }
+
+    fn resolve(&self, key_id: &str) -> Result<Vec<u8>, AppError> {
+        self.cache
+            .read()
+            .unwrap()
+            .get(key_id)
+            .cloned()
+            .ok_or_else(|| AppError::Validation(format!("Unknown keyId: {}", key_id)))
+    }
+}
+
+/// Verifies the `Signature` header on `request`, returning the authenticated
+/// principal on success
+///
+/// Reconstructs the signing string from the pseudo-headers listed in the
+/// `Signature` header's `headers` field (in order), recomputes the `Digest`
+/// of the body to guard against tampering, enforces a replay window on
+/// `Date`, then verifies the signature against the public key for `keyId`.
+pub fn authenticate(request: &Request, resolver: &KeyResolver) -> Result<Principal, AppError> {
+    let signature_header = request
+        .headers()
+        .get("Signature")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::Validation("Missing Signature header".to_string()))?;
+
+    let parsed = parse_signature_header(signature_header)?;
+    verify_required_headers_signed(&parsed.headers)?;
+
+    verify_date_header(request)?;
+    verify_digest_header(request)?;
+
+    let signing_string = build_signing_string(request, &parsed.headers)?;
+    let public_key_der = resolver.resolve(&parsed.key_id)?;
+
+    let public_key = UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, &public_key_der);
+    public_key
+        .verify(signing_string.as_bytes(), &parsed.signature)
+        .map_err(|_| AppError::Validation("Signature verification failed".to_string()))?;
+
+    Ok(Principal { user_id: parsed.key_id })
+}
+
+/// Parses a `Signature: keyId="...",algorithm="...",headers="...",signature="..."`
+/// header into its component fields
+fn parse_signature_header(header: &str) -> Result<ParsedSignature, AppError> {
+    let mut key_id = None;
+    let mut headers = None;
+    let mut signature_b64 = None;
+
+    for field in header.split(',') {
+        let (name, value) = field
+            .split_once('=')
+            .ok_or_else(|| AppError::Validation("Malformed Signature header".to_string()))?;
+        let value = value.trim().trim_matches('"');
+
+        match name.trim() {
+            "keyId" => key_id = Some(value.to_string()),
+            "headers" => headers = Some(value.split_whitespace().map(str::to_string).collect()),
+            "signature" => signature_b64 = Some(value.to_string()),
+            // "algorithm" and any extension params are ignored; verification
+            // always uses RSA PKCS#1 v1.5 with SHA-256 (see `authenticate`)
+            _ => {},
+        }
+    }
+
+    let key_id = key_id.ok_or_else(|| AppError::Validation("Signature header missing keyId".to_string()))?;
+    let headers: Vec<String> =
+        headers.ok_or_else(|| AppError::Validation("Signature header missing headers".to_string()))?;
+    let signature_b64 =
+        signature_b64.ok_or_else(|| AppError::Validation("Signature header missing signature".to_string()))?;
+
+    let signature = base64::engine::general_purpose::STANDARD
+        .decode(signature_b64)
+        .map_err(|_| AppError::Validation("Signature is not valid base64".to_string()))?;
+
+    Ok(ParsedSignature { key_id, headers, signature })
+}
+
+/// Rejects a parsed `Signature` header unless its `headers` field covers
+/// every entry in [`REQUIRED_SIGNED_HEADERS`], so the method, path, date, and
+/// body are all cryptographically bound to the signature rather than left
+/// for the server to trust at face value
+fn verify_required_headers_signed(headers: &[String]) -> Result<(), AppError> {
+    let missing: Vec<&str> = REQUIRED_SIGNED_HEADERS
+        .iter()
+        .filter(|required| !headers.iter().any(|signed| signed == *required))
+        .copied()
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Signature does not cover required header(s): {}",
+            missing.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reconstructs the signing string by concatenating the listed pseudo-headers
+/// in order, one per line, the way the signer did
+fn build_signing_string(request: &Request, header_names: &[String]) -> Result<String, AppError> {
+    let mut lines = Vec::with_capacity(header_names.len());
+
+    for name in header_names {
+        let line = if name == "(request-target)" {
+            format!(
+                "(request-target): {} {}",
+                request.method().as_str().to_lowercase(),
+                request.uri().path()
+            )
+        } else {
+            let value = request
+                .headers()
+                .get(name.as_str())
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| AppError::Validation(format!("Missing signed header: {}", name)))?;
+            format!("{}: {}", name, value)
+        };
+
+        lines.push(line);
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Rejects requests whose `Date` header falls outside `MAX_CLOCK_SKEW` of
+/// now, which blocks a captured signature from being replayed indefinitely
+fn verify_date_header(request: &Request) -> Result<(), AppError> {
+    let date_header = request
+        .headers()
+        .get("date")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::Validation("Missing Date header".to_string()))?;
+
+    let request_time = DateTime::parse_from_rfc2822(date_header)
+        .map_err(|_| AppError::Validation("Date header is not a valid HTTP date".to_string()))?
+        .with_timezone(&Utc);
+
+    if (Utc::now() - request_time).abs() > MAX_CLOCK_SKEW {
+        return Err(AppError::Validation(
+            "Date header outside the allowed replay window".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Recomputes the SHA-256 `Digest` of the request body and rejects the
+/// request if it doesn't match the `Digest` header, which guards against the
+/// signed headers and the actual body diverging
+fn verify_digest_header(request: &Request) -> Result<(), AppError> {
+    let digest_header = request
+        .headers()
+        .get("digest")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::Validation("Missing Digest header".to_string()))?;
+
+    let expected_b64 = digest_header
+        .strip_prefix("SHA-256=")
+        .ok_or_else(|| AppError::Validation("Digest header must use SHA-256".to_string()))?;
+
+    let body_bytes: &[u8] = match request.body() {
+        Body::Text(text) => text.as_bytes(),
+        Body::Binary(bytes) => bytes,
+        Body::Empty => &[],
+    };
+
+    let computed_b64 = base64::engine::general_purpose::STANDARD.encode(Sha256::digest(body_bytes));
+
+    if computed_b64 != expected_b64 {
+        return Err(AppError::Validation("Digest header does not match request body".to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature_header(headers: &str) -> String {
+        format!(
+            r#"keyId="test-key",algorithm="rsa-sha256",headers="{}",signature="{}""#,
+            headers,
+            base64::engine::general_purpose::STANDARD.encode(b"fake-signature")
+        )
+    }
+
+    #[test]
+    fn parse_signature_header_extracts_fields() {
+        let parsed = parse_signature_header(&signature_header("(request-target) host date digest")).unwrap();
+
+        assert_eq!(parsed.key_id, "test-key");
+        assert_eq!(parsed.headers, vec!["(request-target)", "host", "date", "digest"]);
+        assert_eq!(parsed.signature, b"fake-signature");
+    }
+
+    #[test]
+    fn parse_signature_header_rejects_missing_field() {
+        let header = r#"algorithm="rsa-sha256",headers="date",signature="c2ln""#;
+
+        assert!(parse_signature_header(header).is_err());
+    }
+
+    #[test]
+    fn verify_required_headers_signed_accepts_full_coverage() {
+        let headers = vec![
+            "(request-target)".to_string(),
+            "host".to_string(),
+            "date".to_string(),
+            "digest".to_string(),
+        ];
+
+        assert!(verify_required_headers_signed(&headers).is_ok());
+    }
+
+    #[test]
+    fn verify_required_headers_signed_accepts_superset() {
+        let headers = vec![
+            "(request-target)".to_string(),
+            "host".to_string(),
+            "date".to_string(),
+            "digest".to_string(),
+            "content-type".to_string(),
+        ];
+
+        assert!(verify_required_headers_signed(&headers).is_ok());
+    }
+
+    #[test]
+    fn verify_required_headers_signed_rejects_partial_coverage() {
+        let headers = vec!["(request-target)".to_string()];
+
+        assert!(verify_required_headers_signed(&headers).is_err());
+    }
+
+    #[test]
+    fn build_signing_string_reconstructs_request_target_and_headers() {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/items")
+            .header("host", "api.example.com")
+            .header("date", "Tue, 07 Jun 2014 20:51:35 GMT")
+            .body(Body::Empty)
+            .unwrap();
+
+        let header_names = vec!["(request-target)".to_string(), "host".to_string(), "date".to_string()];
+        let signing_string = build_signing_string(&request, &header_names).unwrap();
+
+        assert_eq!(
+            signing_string,
+            "(request-target): post /items\nhost: api.example.com\ndate: Tue, 07 Jun 2014 20:51:35 GMT"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use async_graphql::{Context, EmptySubscription, InputObject, Object, Result as GqlResult, Schema, SimpleObject};
+use aws_sdk_sqs::Client as SqsClient;
+use chrono::Utc;
+use std::sync::Arc;
+use shared::{
+    models::{Item, ItemEvent, ItemEventType},
+    repository::DynamoDbRepository,
+    AppError,
+};
+use crate::auth::Principal;
+
+/// The URL of the SQS events queue, wrapped so it doesn't collide with any
+/// other `String` stored in the GraphQL schema's context data
+pub struct QueueUrl(pub String);
+
+/// The assembled GraphQL schema for this API: queries, mutations, and no subscriptions
+pub type ItemSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Builds the GraphQL schema once at startup
+///
+/// `repo`, `sqs_client`, and `queue_url` are stored as schema-wide context
+/// data since they're fixed for the Lambda's lifetime. The authenticated
+/// `Principal` varies per request, so it's added separately via
+/// `Request::data` in the `POST /graphql` handler rather than here.
+pub fn build_schema(repo: Arc<DynamoDbRepository>, sqs_client: Arc<SqsClient>, queue_url: String) -> ItemSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(repo)
+        .data(sqs_client)
+        .data(QueueUrl(queue_url))
+        .finish()
+}
+
+/// GraphQL projection of `shared::models::Item`, supporting field-level
+/// selection
+///
+/// Attachments aren't exposed here — GraphQL clients keep using
+/// `POST`/`GET /items/{id}/attachments` for those.
+#[derive(SimpleObject)]
+pub struct ItemGql {
+    id: String,
+    name: String,
+    description: Option<String>,
+    created_at: chrono::DateTime<Utc>,
+    classification: String,
+}
+
+impl From<Item> for ItemGql {
+    fn from(item: Item) -> Self {
+        Self {
+            id: item.id,
+            name: item.name,
+            description: item.description,
+            created_at: item.created_at,
+            classification: item.classification,
+        }
+    }
+}
+
+/// Input for the `createItem` mutation
+#[derive(InputObject)]
+pub struct CreateItemInput {
+    id: Option<String>,
+    name: String,
+    description: Option<String>,
+    classification: Option<String>,
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Looks up a single item by ID
+    async fn item(&self, ctx: &Context<'_>, id: String) -> GqlResult<Option<ItemGql>> {
+        let repo = ctx.data::<Arc<DynamoDbRepository>>()?;
+
+        let item = repo.get_item(&id).await.map_err(AppError::DynamoDb).map_err(to_gql_error)?;
+
+        Ok(item.map(ItemGql::from))
+    }
+
+    /// Lists all items
+    async fn items(&self, ctx: &Context<'_>) -> GqlResult<Vec<ItemGql>> {
+        let repo = ctx.data::<Arc<DynamoDbRepository>>()?;
+
+        let items = repo.list_items().await.map_err(AppError::DynamoDb).map_err(to_gql_error)?;
+
+        Ok(items.into_iter().map(ItemGql::from).collect())
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// Creates an item the same way `POST /items` does: validated, written
+    /// to DynamoDB, audited, and published as an `ItemEvent` to SQS
+    async fn create_item(&self, ctx: &Context<'_>, input: CreateItemInput) -> GqlResult<ItemGql> {
+        let repo = ctx.data::<Arc<DynamoDbRepository>>()?;
+        let sqs_client = ctx.data::<Arc<SqsClient>>()?;
+        let queue_url = ctx.data::<QueueUrl>()?;
+        let principal = ctx.data::<Principal>()?;
+
+        let item = Item {
+            id: input.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            name: input.name,
+            description: input.description,
+            created_at: Utc::now(),
+            classification: input.classification.unwrap_or_else(|| "INTERNAL".to_string()),
+            attachments: Vec::new(),
+        };
+
+        crate::validate_item(&item).map_err(to_gql_error)?;
+
+        let audit = crate::build_audit_record(repo, "create", &item, None, "request-id", principal)
+            .await
+            .map_err(to_gql_error)?;
+        repo.create_item_with_audit(&item, &audit).await.map_err(to_gql_error)?;
+
+        let event = ItemEvent {
+            event_type: ItemEventType::Created,
+            item: item.clone(),
+            timestamp: Utc::now(),
+        };
+        publish_event(sqs_client, &queue_url.0, &event).await?;
+
+        Ok(ItemGql::from(item))
+    }
+
+    /// Deletes an item the same way `DELETE /items/{id}` does: audited,
+    /// removed from DynamoDB, and then published as an `ItemEvent` to SQS
+    async fn delete_item(&self, ctx: &Context<'_>, id: String) -> GqlResult<bool> {
+        let repo = ctx.data::<Arc<DynamoDbRepository>>()?;
+        let sqs_client = ctx.data::<Arc<SqsClient>>()?;
+        let queue_url = ctx.data::<QueueUrl>()?;
+        let principal = ctx.data::<Principal>()?;
+
+        let item = repo.get_item(&id).await.map_err(AppError::DynamoDb).map_err(to_gql_error)?
+            .ok_or_else(|| to_gql_error(AppError::NotFound(format!("Item with ID {} not found", id))))?;
+
+        let previous_state = serde_json::to_string(&item).ok();
+        let audit = crate::build_audit_record(repo, "delete", &item, previous_state, "request-id", principal)
+            .await
+            .map_err(to_gql_error)?;
+
+        repo.delete_item_with_audit(&id, &audit).await.map_err(to_gql_error)?;
+
+        let event = ItemEvent {
+            event_type: ItemEventType::Deleted,
+            item,
+            timestamp: Utc::now(),
+        };
+        publish_event(sqs_client, &queue_url.0, &event).await?;
+
+        Ok(true)
+    }
+}
+
+/// Publishes an `ItemEvent` to SQS, the same way the REST handlers do
+async fn publish_event(sqs_client: &SqsClient, queue_url: &str, event: &ItemEvent) -> GqlResult<()> {
+    let event_json = serde_json::to_string(event).map_err(AppError::from).map_err(to_gql_error)?;
+
+    sqs_client.send_message()
+        .queue_url(queue_url)
+        .message_body(event_json)
+        .send()
+        .await
+        .map_err(|e| to_gql_error(AppError::Sqs(e.to_string())))?;
+
+    Ok(())
+}
+
+/// Maps an `AppError` onto a GraphQL error
+///
+/// GraphQL-over-HTTP conventionally always responds 200, so the REST
+/// status this error would have mapped to (400/404/409/500, matching
+/// `handle_request`'s mapping) is surfaced as the `code` extension instead,
+/// for clients to branch on the way they would an HTTP status.
+fn to_gql_error(err: AppError) -> async_graphql::Error {
+    let code = match err {
+        AppError::NotFound(_) => "404",
+        AppError::Validation(_) => "400",
+        AppError::Conflict(_) | AppError::Duplicate(_) => "409",
+        _ => "500",
+    };
+
+    async_graphql::Error::new(err.to_string()).extend_with(|_, e| e.set("code", code))
+}
@@ -1,17 +1,38 @@
-use lambda_http::{run, service_fn, Body, Error, Request, Response};
+use lambda_http::{run_with_streaming_response, service_fn, Body, Error, Request, RequestExt, Response};
 use tracing::{info, error};
 use shared::{
-    models::{Item, ApiResponse, ErrorResponse},
+    models::{Item, ItemWrite, ApiResponse, ErrorResponse},
     repository::DynamoDbRepository,
+    attachments::AttachmentStore,
     config::AppConfig,
     AppError,
 };
 use aws_sdk_sqs::Client as SqsClient;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
 use std::env;
+use std::sync::Arc;
+use std::time::Duration;
 use uuid;
-use md5;
-use chrono::{Utc};
+use chrono::{DateTime, Utc};
+
+mod auth;
+use auth::KeyResolver;
+
+mod router;
+use router::{Params, Router};
+
+mod sse;
+
+mod streaming;
+use streaming::StreamableBody;
+
+mod graphql;
+use graphql::ItemSchema;
 
 /// Test module for unit testing the API handler
 #[cfg(test)]
@@ -36,9 +57,17 @@ async fn main() -> Result<(), Error> {
     
     // Initialize AWS SDK clients
     let aws_config = aws_config::load_from_env().await;
-    let repo = DynamoDbRepository::new(&aws_config);
-    let sqs_client = SqsClient::new(&aws_config);
-    
+    // Wrapped in `Arc` (rather than the plain owned values used elsewhere)
+    // because the GraphQL schema below needs to store them as 'static
+    // context data; the streaming handlers (`get_items`/`stream_item_events`)
+    // take their own `Arc` clone, and the other REST handlers keep taking
+    // `&DynamoDbRepository` / `&SqsClient` since `&Arc<T>` derefs to `&T`.
+    let repo = Arc::new(DynamoDbRepository::new(&aws_config));
+    let sqs_client = Arc::new(SqsClient::new(&aws_config));
+    let key_resolver = KeyResolver::from_env();
+    let attachment_store = AttachmentStore::new(&aws_config, config.attachments_bucket.clone());
+    let attachment_url_expiry_secs = config.attachment_url_expiry_secs;
+
     // Get SQS queue URL from environment or construct a default one
     let queue_url = env::var("EVENT_QUEUE_URL").unwrap_or_else(|_| {
         let stack_name = env::var("AWS_LAMBDA_FUNCTION_NAME")
@@ -52,9 +81,23 @@ async fn main() -> Result<(), Error> {
     
     info!("Using SQS queue URL: {}", queue_url);
 
-    // Run the Lambda service with our request handler
-    run(service_fn(|event: Request| {
-        handle_request(event, &repo, &sqs_client, &queue_url)
+    let graphql_schema = graphql::build_schema(repo.clone(), sqs_client.clone(), queue_url.clone());
+
+    // `run_with_streaming_response` (rather than `run`) is what lets
+    // `get_items`/`stream_item_events` hand back a `StreamableBody::Streaming`
+    // whose frames are flushed to the client as they're produced, instead of
+    // the whole response being buffered before anything is sent.
+    run_with_streaming_response(service_fn(|event: Request| {
+        handle_request(
+            event,
+            &repo,
+            &sqs_client,
+            &queue_url,
+            &key_resolver,
+            &attachment_store,
+            attachment_url_expiry_secs,
+            &graphql_schema,
+        )
     })).await?;
 
     Ok(())
@@ -62,8 +105,9 @@ async fn main() -> Result<(), Error> {
 
 /// Main request handler for the API Lambda
 ///
-/// This function routes incoming HTTP requests to the appropriate handler function
-/// based on the HTTP method and path.
+/// This builds a [`Router`] table of `METHOD /pattern => handler`
+/// registrations for the current request and dispatches to whichever one
+/// matches, instead of a hand-written match on `(method, path)`.
 ///
 /// # Arguments
 ///
@@ -71,60 +115,127 @@ async fn main() -> Result<(), Error> {
 /// * `repo` - The DynamoDB repository for data access
 /// * `sqs_client` - The SQS client for sending events
 /// * `queue_url` - The URL of the SQS queue for events
+/// * `key_resolver` - Resolves public keys for verifying request signatures
+/// * `attachment_store` - Issues presigned S3 URLs for item attachments
+/// * `attachment_url_expiry_secs` - How long presigned attachment URLs stay valid
+/// * `graphql_schema` - The GraphQL schema backing `POST /graphql`
 ///
 /// # Returns
 ///
-/// * `Result<Response<Body>, Error>` - The HTTP response or an error
+/// * `Result<Response<StreamableBody>, Error>` - The HTTP response or an error
 async fn handle_request(
     event: Request,
-    repo: &DynamoDbRepository,
-    sqs_client: &SqsClient,
+    repo: &Arc<DynamoDbRepository>,
+    sqs_client: &Arc<SqsClient>,
     queue_url: &str,
-) -> Result<Response<Body>, Error> {
+    key_resolver: &KeyResolver,
+    attachment_store: &AttachmentStore,
+    attachment_url_expiry_secs: u64,
+    graphql_schema: &ItemSchema,
+) -> Result<Response<StreamableBody>, Error> {
     let path = event.uri().path().to_string();
     let method = event.method().as_str().to_string();
-    
+
     info!("Handling request: {} {}", method, path);
 
-    let result = match (method.as_str(), path.as_str()) {
-        // Route GET /items to get_items handler
-        ("GET", "/items") => get_items(repo).await,
-        
-        // Route GET /items/{id} to get_item handler
-        ("GET", p) if p.starts_with("/items/") => {
-            let id = p.trim_start_matches("/items/");
-            get_item(repo, id).await
+    // POST and DELETE routes require a verified request signature (including
+    // `POST /graphql`, for queries as well as mutations); GET routes stay open.
+    // The resulting principal's `user_id` is what gets recorded on audit
+    // records, replacing the previous hardcoded "system" user.
+    let principal = if matches!(method.as_str(), "POST" | "DELETE") {
+        match auth::authenticate(&event, key_resolver) {
+            Ok(principal) => principal,
+            Err(err) => {
+                error!("Authentication failed: {:?}", err);
+                return Ok(error_response(err.to_string(), 401));
+            },
+        }
+    } else {
+        auth::Principal { user_id: "anonymous".to_string() }
+    };
+
+    let mut router = Router::new();
+    crate::route!(router, {
+        "GET" "/items" => |req: Request, _params: Params| async move {
+            let accept_event_stream = req.headers().get("accept")
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.contains("text/event-stream"))
+                .unwrap_or(false);
+            let query = req.query_string_parameters();
+            let classification = query.first("classification").map(str::to_string);
+            let cursor = query.first("cursor").map(str::to_string);
+
+            match classification {
+                Some(classification) => get_items_by_classification(repo, &classification, cursor, accept_event_stream).await,
+                None => get_items(repo.clone(), accept_event_stream).await,
+            }
         },
-        
-        // Route POST /items to create_item handler
-        ("POST", "/items") => {
-            let body = event.body();
-            let item: Item = match body {
-                Body::Text(text) => serde_json::from_str(text)?,
-                Body::Binary(bytes) => serde_json::from_slice(bytes)?,
-                _ => return Ok(error_response("Invalid request body".to_string(), 400)),
-            };
-            create_item(repo, sqs_client, queue_url, item).await
+        "GET" "/items/events" => |_req: Request, _params: Params| async move {
+            stream_item_events(sqs_client.clone(), queue_url.to_string()).await
         },
-        
-        // Route DELETE /items/{id} to delete_item handler
-        ("DELETE", p) if p.starts_with("/items/") => {
-            let id = p.trim_start_matches("/items/");
-            delete_item(repo, sqs_client, queue_url, id).await
+        "GET" "/items/{id}" => |_req: Request, params: Params| async move {
+            get_item(repo, &params["id"]).await
         },
-        
-        // Return 404 for any other routes
-        _ => Ok(error_response("Not found".to_string(), 404)),
-    };
+        "GET" "/audit/{resource_id}" => |_req: Request, params: Params| async move {
+            get_audit_chain(repo, &params["resource_id"]).await
+        },
+        "POST" "/graphql" => |req: Request, _params: Params| async move {
+            let graphql_request = parse_body::<async_graphql::Request>(&req)?;
+            handle_graphql(graphql_schema, graphql_request, principal.clone()).await
+        },
+        "POST" "/items/batch" => |req: Request, _params: Params| async move {
+            let operations = parse_body::<Vec<ItemWrite>>(&req)?;
+            batch_write_items(repo, operations).await
+        },
+        "POST" "/items" => |req: Request, _params: Params| async move {
+            let item = parse_body::<Item>(&req)?;
+            create_item(repo, sqs_client, queue_url, item, &principal).await
+        },
+        "DELETE" "/items/{id}" => |_req: Request, params: Params| async move {
+            delete_item(repo, sqs_client, queue_url, &params["id"], &principal).await
+        },
+        "POST" "/items/{id}/attachments" => |req: Request, params: Params| async move {
+            let request = parse_body::<AttachmentUploadRequest>(&req)?;
+            create_attachment(
+                repo,
+                sqs_client,
+                queue_url,
+                attachment_store,
+                &params["id"],
+                request,
+                attachment_url_expiry_secs,
+                &principal,
+            ).await
+        },
+        "GET" "/items/{id}/attachments/{name}" => |_req: Request, params: Params| async move {
+            get_attachment_download_url(
+                repo,
+                attachment_store,
+                &params["id"],
+                &params["name"],
+                attachment_url_expiry_secs,
+            ).await
+        },
+    });
 
     // Handle errors and convert to appropriate HTTP responses
-    match result {
+    match router.dispatch(&method, &path, event).await {
         Ok(response) => Ok(response),
+        Err(AppError::MethodNotAllowed(allowed)) => Ok(Response::builder()
+            .status(405)
+            .header("Allow", allowed)
+            .header("Content-Type", "application/json")
+            .body(StreamableBody::buffered(
+                serde_json::to_string(&ErrorResponse { message: "Method not allowed".to_string() })
+                    .unwrap_or_default(),
+            ))
+            .unwrap_or_else(|_| error_response("Method not allowed".to_string(), 405))),
         Err(err) => {
             error!("Error processing request: {:?}", err);
             let status_code = match err {
                 AppError::NotFound(_) => 404,
                 AppError::Validation(_) => 400,
+                AppError::Conflict(_) | AppError::Duplicate(_) => 409,
                 _ => 500,
             };
             Ok(error_response(err.to_string(), status_code))
@@ -132,38 +243,267 @@ async fn handle_request(
     }
 }
 
-/// Handler for GET /items endpoint
+/// Parses a request body as JSON into `T`, 400-ing (via `AppError::Validation`)
+/// if the body is empty
+fn parse_body<T: serde::de::DeserializeOwned>(request: &Request) -> Result<T, AppError> {
+    match request.body() {
+        Body::Text(text) => Ok(serde_json::from_str(text)?),
+        Body::Binary(bytes) => Ok(serde_json::from_slice(bytes)?),
+        Body::Empty => Err(AppError::Validation("Request body is required".to_string())),
+    }
+}
+
+/// How many items [`DynamoDbRepository::list_items_stream`] pulls per page
+const LIST_ITEMS_PAGE_SIZE: i32 = 50;
+
+/// How many SSE item frames to send between `: keep-alive` comments
+const SSE_KEEP_ALIVE_EVERY: u64 = 25;
+
+/// Handler for `GET /items` with no `classification` query param
 ///
-/// Retrieves all items from the database and returns them as a JSON array.
+/// Streams all items from the database as they come back from the scan,
+/// rather than buffering the whole table before responding — as a JSON
+/// array, or — when `accept_event_stream` is set (the request's `Accept`
+/// header is `text/event-stream`) — as a sequence of SSE `data:` frames, one
+/// per item. Requests for a single classification are routed to
+/// `get_items_by_classification` instead, which queries the
+/// `ByClassification` GSI rather than scanning the whole table.
 ///
 /// # Arguments
 ///
 /// * `repo` - The DynamoDB repository for data access
+/// * `accept_event_stream` - Whether the client asked for `text/event-stream`
 ///
 /// # Returns
 ///
-/// * `Result<Response<Body>, AppError>` - A JSON response with all items or an error
-async fn get_items(repo: &DynamoDbRepository) -> Result<Response<Body>, AppError> {
-    // Retrieve all items from the database
-    let items = repo.list_items().await?;
-    
-    // Create a successful response
-    let response = ApiResponse {
-        status_code: 200,
-        body: items,
-    };
-    
-    // Serialize the response body to JSON
-    let body = serde_json::to_string(&response.body)?;
-    
-    // Build and return the HTTP response
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON or SSE response with all items, or an error
+async fn get_items(repo: Arc<DynamoDbRepository>, accept_event_stream: bool) -> Result<Response<StreamableBody>, AppError> {
+    if accept_event_stream {
+        return Ok(Response::builder()
+            .status(200)
+            .header("Content-Type", "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .body(StreamableBody::streaming(stream_items_sse(repo)))
+            .map_err(|e| AppError::Internal(e.to_string()))?);
+    }
+
     Ok(Response::builder()
-        .status(response.status_code)
+        .status(200)
         .header("Content-Type", "application/json")
-        .body(Body::from(body))
+        .body(StreamableBody::streaming(stream_items_json(repo)))
         .map_err(|e| AppError::Internal(e.to_string()))?)
 }
 
+/// How many items [`DynamoDbRepository::list_items_by_classification`]
+/// returns per page
+const CLASSIFICATION_PAGE_SIZE: i32 = 50;
+
+/// A single page of classification-filtered items from `GET
+/// /items?classification=...`, together with the cursor to fetch the next one
+#[derive(Debug, Serialize)]
+struct ItemPage {
+    /// This page's items, newest first
+    items: Vec<Item>,
+
+    /// Opaque cursor to pass back as the `cursor` query param for the next
+    /// page, or `None` if this was the last page
+    next_cursor: Option<String>,
+}
+
+/// Handler for `GET /items?classification=...`
+///
+/// Queries the `ByClassification` GSI for a single classification instead of
+/// scanning the whole table, returning one bounded page of
+/// [`CLASSIFICATION_PAGE_SIZE`] items at a time rather than the unbounded
+/// stream `get_items` returns for the unfiltered listing. Pass the previous
+/// page's `next_cursor` back as the `cursor` query param to fetch the next
+/// page; omit it for the first page.
+///
+/// # Arguments
+///
+/// * `repo` - The DynamoDB repository for data access
+/// * `classification` - The classification to filter by
+/// * `cursor` - An opaque pagination cursor from a previous page, if any
+/// * `accept_event_stream` - Whether the client asked for `text/event-stream`
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON or SSE response with the page of items, or an error
+async fn get_items_by_classification(
+    repo: &DynamoDbRepository,
+    classification: &str,
+    cursor: Option<String>,
+    accept_event_stream: bool,
+) -> Result<Response<StreamableBody>, AppError> {
+    let start_key = cursor.as_deref().map(DynamoDbRepository::decode_cursor).transpose()?;
+
+    let (items, last_evaluated_key) = repo
+        .list_items_by_classification(classification, CLASSIFICATION_PAGE_SIZE, start_key)
+        .await
+        .map_err(AppError::DynamoDb)?;
+    let next_cursor = last_evaluated_key.as_ref().and_then(DynamoDbRepository::encode_cursor);
+
+    if accept_event_stream {
+        let mut body = String::new();
+        for (index, item) in items.iter().enumerate() {
+            if let Ok(data) = serde_json::to_string(item) {
+                body.push_str(&sse::format_event(Some(index as u64), None, &data));
+            }
+        }
+
+        return Ok(Response::builder()
+            .status(200)
+            .header("Content-Type", "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .body(StreamableBody::buffered(body))
+            .map_err(|e| AppError::Internal(e.to_string()))?);
+    }
+
+    let body = serde_json::to_string(&ItemPage { items, next_cursor })?;
+
+    Ok(Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
+/// Streams `GET /items`' JSON array body, one item at a time, as
+/// `repo.list_items_stream` pages through the table
+fn stream_items_json(repo: Arc<DynamoDbRepository>) -> impl Stream<Item = Result<Bytes, Infallible>> {
+    async_stream::stream! {
+        yield Ok(Bytes::from_static(b"["));
+
+        let mut first = true;
+        let mut items = Box::pin(repo.list_items_stream(LIST_ITEMS_PAGE_SIZE));
+
+        while let Some(next) = items.next().await {
+            match next {
+                Ok(item) => {
+                    let Ok(json) = serde_json::to_string(&item) else { continue };
+
+                    if !first {
+                        yield Ok(Bytes::from_static(b","));
+                    }
+                    first = false;
+                    yield Ok(Bytes::from(json));
+                },
+                Err(err) => {
+                    error!("Error while streaming items: {:?}", err);
+                    break;
+                },
+            }
+        }
+
+        yield Ok(Bytes::from_static(b"]"));
+    }
+}
+
+/// Streams `GET /items` (with `Accept: text/event-stream`) as SSE frames, one
+/// `data:` frame per item as `repo.list_items_stream` pages through the
+/// table, with a `: keep-alive` comment interleaved every
+/// `SSE_KEEP_ALIVE_EVERY` frames so a long listing doesn't read as an idle
+/// connection
+fn stream_items_sse(repo: Arc<DynamoDbRepository>) -> impl Stream<Item = Result<Bytes, Infallible>> {
+    async_stream::stream! {
+        let mut index: u64 = 0;
+        let mut items = Box::pin(repo.list_items_stream(LIST_ITEMS_PAGE_SIZE));
+
+        while let Some(next) = items.next().await {
+            match next {
+                Ok(item) => {
+                    let Ok(data) = serde_json::to_string(&item) else { continue };
+
+                    yield Ok(Bytes::from(sse::format_event(Some(index), None, &data)));
+                    index += 1;
+
+                    if index % SSE_KEEP_ALIVE_EVERY == 0 {
+                        yield Ok(Bytes::from(sse::keep_alive_comment()));
+                    }
+                },
+                Err(err) => {
+                    error!("Error while streaming items: {:?}", err);
+                    break;
+                },
+            }
+        }
+    }
+}
+
+/// Handler for GET /items/events endpoint
+///
+/// Opens a long-lived SSE stream that repeatedly long-polls the events SQS
+/// queue (`WaitTimeSeconds`) and forwards each `ItemEvent` it receives, with
+/// `event:` set to the event's `ItemEventType` and `id:` a counter that's
+/// monotonic for the life of the connection rather than reset on every poll.
+///
+/// Messages are received but deliberately never deleted — this endpoint is
+/// a read-only observability view onto the same queue the event processor
+/// Lambda consumes for real, and deleting them here would rob that Lambda
+/// of messages it never got a chance to process.
+///
+/// # Arguments
+///
+/// * `sqs_client` - The SQS client for receiving events
+/// * `queue_url` - The URL of the SQS queue for events
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A streaming SSE response of events as they arrive, or an error
+async fn stream_item_events(sqs_client: Arc<SqsClient>, queue_url: String) -> Result<Response<StreamableBody>, AppError> {
+    Ok(Response::builder()
+        .status(200)
+        .header("Content-Type", "text/event-stream")
+        .header("Cache-Control", "no-cache")
+        .body(StreamableBody::streaming(item_events_stream(sqs_client, queue_url)))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
+/// Tails the events SQS queue for as long as the Lambda invocation stays
+/// alive, yielding an SSE frame for each `ItemEvent` it receives
+fn item_events_stream(sqs_client: Arc<SqsClient>, queue_url: String) -> impl Stream<Item = Result<Bytes, Infallible>> {
+    async_stream::stream! {
+        let mut next_id: u64 = 0;
+
+        loop {
+            let response = match sqs_client
+                .receive_message()
+                .queue_url(&queue_url)
+                .max_number_of_messages(10)
+                .wait_time_seconds(20)
+                .send()
+                .await
+            {
+                Ok(response) => response,
+                Err(err) => {
+                    error!("Error long-polling events queue: {:?}", err);
+                    break;
+                },
+            };
+
+            let messages = response.messages().unwrap_or_default();
+
+            if messages.is_empty() {
+                yield Ok(Bytes::from(sse::keep_alive_comment()));
+                continue;
+            }
+
+            for message in messages {
+                let Some(raw) = message.body() else { continue };
+                let Ok(item_event) = serde_json::from_str::<shared::models::ItemEvent>(raw) else { continue };
+                let Ok(data) = serde_json::to_string(&item_event) else { continue };
+
+                yield Ok(Bytes::from(sse::format_event(
+                    Some(next_id),
+                    Some(&item_event.event_type.to_string()),
+                    &data,
+                )));
+                next_id += 1;
+            }
+        }
+    }
+}
+
 /// Handler for GET /items/{id} endpoint
 ///
 /// Retrieves a specific item by ID from the database.
@@ -175,11 +515,11 @@ async fn get_items(repo: &DynamoDbRepository) -> Result<Response<Body>, AppError
 ///
 /// # Returns
 ///
-/// * `Result<Response<Body>, AppError>` - A JSON response with the item or an error
-async fn get_item(repo: &DynamoDbRepository, id: &str) -> Result<Response<Body>, AppError> {
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON response with the item or an error
+async fn get_item(repo: &DynamoDbRepository, id: &str) -> Result<Response<StreamableBody>, AppError> {
     // Retrieve the item from the database
     let item = repo.get_item(id).await?;
-    
+
     match item {
         Some(item) => {
             // Create a successful response
@@ -187,15 +527,15 @@ async fn get_item(repo: &DynamoDbRepository, id: &str) -> Result<Response<Body>,
                 status_code: 200,
                 body: item,
             };
-            
+
             // Serialize the response body to JSON
             let body = serde_json::to_string(&response.body)?;
-            
+
             // Build and return the HTTP response
             Ok(Response::builder()
                 .status(response.status_code)
                 .header("Content-Type", "application/json")
-                .body(Body::from(body))
+                .body(StreamableBody::buffered(body))
                 .map_err(|e| AppError::Internal(e.to_string()))?)
         },
         None => Err(AppError::NotFound(format!("Item with ID {} not found", id))),
@@ -272,53 +612,177 @@ fn mask_sensitive_data(data: &str) -> String {
     format!("{}{}", visible, masked)
 }
 
-/// Creates an audit record for an action
+/// Builds (but does not persist) the next link in the tamper-evident audit
+/// chain for `item.id`.
+///
+/// Each record's `hash` commits to the previous record's `hash`
+/// (`prev_hash`, looked up from the audit table) together with its own
+/// fields, via [`chain_hash`]. Altering or deleting any past record breaks
+/// the chain from that point forward, which [`verify_chain`] detects.
 ///
-/// This function creates an audit record for an action performed on an item.
+/// The caller persists the returned record together with the item it's
+/// about — `create_item`/`delete_item` write it atomically via
+/// `DynamoDbRepository::create_item_with_audit`/`delete_item_with_audit`, so
+/// an item can never land (or be removed) without its audit record
+/// surviving a crash between what would otherwise be two separate writes.
 ///
 /// # Arguments
 ///
+/// * `repo` - The DynamoDB repository for data access
 /// * `action` - The action performed (create, update, delete)
 /// * `item` - The item affected
 /// * `previous_state` - The previous state of the item (for updates and deletes)
 /// * `request_id` - The ID of the request that triggered the action
+/// * `principal` - The authenticated principal who performed the action
 ///
 /// # Returns
 ///
-/// * `AuditRecord` - The audit record
-fn create_audit_record(
+/// * `Result<AuditRecord, AppError>` - The next (unpersisted) audit record, or an error
+async fn build_audit_record(
+    repo: &DynamoDbRepository,
     action: &str,
     item: &Item,
     previous_state: Option<String>,
     request_id: &str,
-) -> shared::models::AuditRecord {
+    principal: &auth::Principal,
+) -> Result<shared::models::AuditRecord, AppError> {
     let new_state = if action != "delete" {
         Some(serde_json::to_string(item).unwrap_or_default())
     } else {
         None
     };
-    
-    // In a real application, you would get the user ID from authentication
-    let user_id = "system".to_string();
-    
-    // Create a hash of the item for non-repudiation
-    let item_json = serde_json::to_string(item).unwrap_or_default();
-    let item_hash = format!("{:x}", md5::compute(item_json.as_bytes()));
-    
-    shared::models::AuditRecord {
-        event_id: uuid::Uuid::new_v4().to_string(),
-        user_id,
+
+    let event_id = uuid::Uuid::new_v4().to_string();
+    let timestamp = Utc::now();
+
+    let prev_hash = repo.latest_audit_record(&item.id).await.map_err(AppError::DynamoDb)?
+        .and_then(|record| record.hash);
+
+    let hash = chain_hash(prev_hash.as_deref(), &event_id, action, &item.id, &timestamp, new_state.as_deref());
+
+    Ok(shared::models::AuditRecord {
+        event_id,
+        user_id: principal.user_id.clone(),
         action: action.to_string(),
         resource_id: item.id.clone(),
         resource_type: "item".to_string(),
-        timestamp: Utc::now(),
+        timestamp,
         previous_state,
         new_state,
         request_id: request_id.to_string(),
-        hash: Some(item_hash),
+        prev_hash,
+        hash: Some(hash),
+    })
+}
+
+/// Computes one link's hash in a resource's audit chain:
+/// `SHA256(prev_hash || event_id || action || resource_id || timestamp ||
+/// new_state)`, hex-encoded. `prev_hash`/`new_state` are hashed as empty
+/// strings when absent (first link in the chain / delete action).
+fn chain_hash(
+    prev_hash: Option<&str>,
+    event_id: &str,
+    action: &str,
+    resource_id: &str,
+    timestamp: &DateTime<Utc>,
+    new_state: Option<&str>,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash.unwrap_or("").as_bytes());
+    hasher.update(event_id.as_bytes());
+    hasher.update(action.as_bytes());
+    hasher.update(resource_id.as_bytes());
+    hasher.update(timestamp.to_rfc3339().as_bytes());
+    hasher.update(new_state.unwrap_or("").as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// The result of walking and validating a resource's audit chain
+#[derive(Debug, Serialize)]
+struct ChainVerification {
+    /// The resource's audit records, oldest first
+    records: Vec<shared::models::AuditRecord>,
+
+    /// Whether every record's hash matches its recomputed chain hash and
+    /// correctly commits to the previous record's hash
+    valid: bool,
+
+    /// The `event_id` of the first record whose chain link doesn't hold, if any
+    first_broken_link: Option<String>,
+}
+
+/// Walks `records` (oldest first) recomputing each one's chain hash and
+/// checking it against the recorded `hash` and `prev_hash`, stopping the
+/// search at the first record whose link doesn't hold.
+///
+/// # Arguments
+///
+/// * `records` - The resource's audit records, oldest first
+///
+/// # Returns
+///
+/// * `ChainVerification` - The records together with the overall verdict and the first broken link, if any
+fn verify_chain(records: Vec<shared::models::AuditRecord>) -> ChainVerification {
+    let mut expected_prev_hash: Option<String> = None;
+    let mut first_broken_link = None;
+
+    for record in &records {
+        if first_broken_link.is_none() {
+            let recomputed = chain_hash(
+                expected_prev_hash.as_deref(),
+                &record.event_id,
+                &record.action,
+                &record.resource_id,
+                &record.timestamp,
+                record.new_state.as_deref(),
+            );
+
+            let link_holds = record.prev_hash == expected_prev_hash && record.hash.as_deref() == Some(recomputed.as_str());
+
+            if !link_holds {
+                first_broken_link = Some(record.event_id.clone());
+            }
+        }
+
+        expected_prev_hash = record.hash.clone();
+    }
+
+    ChainVerification {
+        valid: first_broken_link.is_none(),
+        first_broken_link,
+        records,
     }
 }
 
+/// Handler for GET /audit/{resource_id} endpoint
+///
+/// Walks the resource's audit chain in chronological order, verifying every
+/// link, and returns the chain together with the verification verdict.
+///
+/// # Arguments
+///
+/// * `repo` - The DynamoDB repository for data access
+/// * `resource_id` - The ID of the resource whose audit chain to fetch
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON response with the verified chain or an error
+async fn get_audit_chain(repo: &DynamoDbRepository, resource_id: &str) -> Result<Response<StreamableBody>, AppError> {
+    let records = repo.get_audit_chain(resource_id).await.map_err(AppError::DynamoDb)?;
+
+    if records.is_empty() {
+        return Err(AppError::NotFound(format!("No audit records found for resource {}", resource_id)));
+    }
+
+    let body = serde_json::to_string(&verify_chain(records))?;
+
+    Ok(Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
 /// Handler for POST /items endpoint
 ///
 /// Creates a new item in the database and sends a creation event to SQS.
@@ -329,27 +793,27 @@ fn create_audit_record(
 /// * `sqs_client` - The SQS client for sending events
 /// * `queue_url` - The URL of the SQS queue for events
 /// * `item` - The item to create
+/// * `principal` - The authenticated principal performing the create
 ///
 /// # Returns
 ///
-/// * `Result<Response<Body>, AppError>` - A JSON response with the created item or an error
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON response with the created item or an error
 async fn create_item(
     repo: &DynamoDbRepository,
     sqs_client: &SqsClient,
     queue_url: &str,
     item: Item,
-) -> Result<Response<Body>, AppError> {
+    principal: &auth::Principal,
+) -> Result<Response<StreamableBody>, AppError> {
     // Validate item
     validate_item(&item)?;
-    
-    // Save item to DynamoDB
-    repo.create_item(&item).await?;
-    
-    // Create an audit record
-    let audit = create_audit_record("create", &item, None, "request-id");
-    
-    // In a real application, you would store the audit record
-    // For now, we'll just log it
+
+    // Build the next link in this item's audit chain and write it together
+    // with the item itself in a single transaction, so the item can never
+    // land without its audit record
+    let audit = build_audit_record(repo, "create", &item, None, "request-id", principal).await?;
+    repo.create_item_with_audit(&item, &audit).await?;
+
     info!(
         action = %audit.action,
         resource_id = %audit.resource_id,
@@ -389,7 +853,43 @@ async fn create_item(
     Ok(Response::builder()
         .status(response.status_code)
         .header("Content-Type", "application/json")
-        .body(Body::from(body))
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
+/// Handler for POST /items/batch endpoint
+///
+/// Applies a batch of create/delete operations in DynamoDB transactions of
+/// at most 25 operations each (see `DynamoDbRepository::batch_write_items`):
+/// a batch of up to 25 operations takes effect all together or not at all,
+/// but a larger batch is not atomic as a whole, since chunks written before
+/// a failing one stay applied. Every
+/// `ItemWrite::Create` is run through the same [`validate_item`] checks as
+/// `POST /items`, so the batch endpoint can't be used to bypass them.
+///
+/// # Arguments
+///
+/// * `repo` - The DynamoDB repository for data access
+/// * `operations` - The create/delete operations to apply atomically
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A 204 response or an error
+async fn batch_write_items(
+    repo: &DynamoDbRepository,
+    operations: Vec<ItemWrite>,
+) -> Result<Response<StreamableBody>, AppError> {
+    for operation in &operations {
+        if let ItemWrite::Create(item) = operation {
+            validate_item(item)?;
+        }
+    }
+
+    repo.batch_write_items(&operations).await?;
+
+    Ok(Response::builder()
+        .status(204)
+        .body(StreamableBody::buffered(Bytes::new()))
         .map_err(|e| AppError::Internal(e.to_string()))?)
 }
 
@@ -403,30 +903,30 @@ async fn create_item(
 /// * `sqs_client` - The SQS client for sending events
 /// * `queue_url` - The URL of the SQS queue for events
 /// * `id` - The ID of the item to delete
+/// * `principal` - The authenticated principal performing the delete
 ///
 /// # Returns
 ///
-/// * `Result<Response<Body>, AppError>` - A success response or an error
+/// * `Result<Response<StreamableBody>, AppError>` - A success response or an error
 async fn delete_item(
     repo: &DynamoDbRepository,
     sqs_client: &SqsClient,
     queue_url: &str,
     id: &str,
-) -> Result<Response<Body>, AppError> {
+    principal: &auth::Principal,
+) -> Result<Response<StreamableBody>, AppError> {
     // Check if item exists
     let item = repo.get_item(id).await?;
-    
+
     match item {
         Some(item) => {
-            // Create an audit record with the previous state
+            // Build the next link in this item's audit chain, recording its
+            // state immediately before deletion, and delete the item
+            // together with that audit record in a single transaction
             let previous_state = serde_json::to_string(&item).ok();
-            let audit = create_audit_record("delete", &item, previous_state, "request-id");
-            
-            // Delete item from DynamoDB
-            repo.delete_item(id).await?;
-            
-            // In a real application, you would store the audit record
-            // For now, we'll just log it
+            let audit = build_audit_record(repo, "delete", &item, previous_state, "request-id", principal).await?;
+            repo.delete_item_with_audit(id, &audit).await?;
+
             info!(
                 action = %audit.action,
                 resource_id = %audit.resource_id,
@@ -456,13 +956,179 @@ async fn delete_item(
             // Build and return a 204 No Content response
             Ok(Response::builder()
                 .status(204)
-                .body(Body::Empty)
+                .body(StreamableBody::buffered(Bytes::new()))
                 .map_err(|e| AppError::Internal(e.to_string()))?)
         },
         None => Err(AppError::NotFound(format!("Item with ID {} not found", id))),
     }
 }
 
+/// Request body for POST /items/{id}/attachments
+///
+/// The client holds the attachment's bytes locally and computes
+/// `size_bytes`/`sha256` itself; the server never sees the bytes, only this
+/// metadata, and the client uploads directly to the presigned URL returned
+/// in the response.
+#[derive(Debug, Deserialize)]
+struct AttachmentUploadRequest {
+    name: String,
+    content_type: String,
+    size_bytes: i64,
+    sha256: String,
+}
+
+/// Handler for POST /items/{id}/attachments endpoint
+///
+/// Records the attachment's metadata on the item, emits an
+/// `AttachmentAdded` event to SQS, and returns a presigned S3 URL the
+/// client uploads the attachment's bytes to directly, bypassing the
+/// Lambda payload limit.
+///
+/// # Arguments
+///
+/// * `repo` - The DynamoDB repository for data access
+/// * `sqs_client` - The SQS client for sending events
+/// * `queue_url` - The URL of the SQS queue for events
+/// * `attachment_store` - Issues presigned S3 URLs for attachments
+/// * `item_id` - The ID of the item to attach the file to
+/// * `request` - The attachment's name, content type, size, and hash
+/// * `expiry_secs` - How long the presigned upload URL stays valid
+/// * `principal` - The authenticated principal performing the upload
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON response with the upload URL or an error
+async fn create_attachment(
+    repo: &DynamoDbRepository,
+    sqs_client: &SqsClient,
+    queue_url: &str,
+    attachment_store: &AttachmentStore,
+    item_id: &str,
+    request: AttachmentUploadRequest,
+    expiry_secs: u64,
+    principal: &auth::Principal,
+) -> Result<Response<StreamableBody>, AppError> {
+    let s3_key = AttachmentStore::key_for(item_id, &request.name)?;
+
+    let attachment = shared::models::Attachment {
+        name: request.name,
+        s3_key: s3_key.clone(),
+        content_type: request.content_type.clone(),
+        size_bytes: request.size_bytes,
+        sha256: request.sha256,
+    };
+
+    let item = repo.add_attachment(item_id, attachment).await?;
+
+    info!(
+        resource_id = %item_id,
+        user_id = %principal.user_id,
+        "Attachment added to item"
+    );
+
+    let event = shared::models::ItemEvent {
+        event_type: shared::models::ItemEventType::AttachmentAdded,
+        item,
+        timestamp: chrono::Utc::now(),
+    };
+    let event_json = serde_json::to_string(&event)?;
+
+    sqs_client.send_message()
+        .queue_url(queue_url)
+        .message_body(event_json)
+        .send()
+        .await
+        .map_err(|e| AppError::Sqs(e.to_string()))?;
+
+    let upload_url = attachment_store
+        .presign_upload(&s3_key, &request.content_type, Duration::from_secs(expiry_secs))
+        .await?;
+
+    let body = serde_json::to_string(&json!({ "upload_url": upload_url }))?;
+
+    Ok(Response::builder()
+        .status(201)
+        .header("Content-Type", "application/json")
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
+/// Handler for GET /items/{id}/attachments/{name} endpoint
+///
+/// Looks up the attachment's S3 key on the item and returns a presigned URL
+/// the client can `GET` the attachment's bytes from directly.
+///
+/// # Arguments
+///
+/// * `repo` - The DynamoDB repository for data access
+/// * `attachment_store` - Issues presigned S3 URLs for attachments
+/// * `item_id` - The ID of the item the attachment belongs to
+/// * `name` - The attachment's name
+/// * `expiry_secs` - How long the presigned download URL stays valid
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - A JSON response with the download URL or an error
+async fn get_attachment_download_url(
+    repo: &DynamoDbRepository,
+    attachment_store: &AttachmentStore,
+    item_id: &str,
+    name: &str,
+    expiry_secs: u64,
+) -> Result<Response<StreamableBody>, AppError> {
+    let item = repo.get_item(item_id).await?
+        .ok_or_else(|| AppError::NotFound(format!("Item with ID {} not found", item_id)))?;
+
+    let attachment = item.attachments.iter()
+        .find(|attachment| attachment.name == name)
+        .ok_or_else(|| AppError::NotFound(format!("Attachment {} not found on item {}", name, item_id)))?;
+
+    let download_url = attachment_store
+        .presign_download(&attachment.s3_key, Duration::from_secs(expiry_secs))
+        .await?;
+
+    let body = serde_json::to_string(&json!({ "download_url": download_url }))?;
+
+    Ok(Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
+/// Handler for POST /graphql endpoint
+///
+/// Executes `graphql_request` against `graphql_schema`, with `principal`
+/// attached as per-request context data (the schema's own context data —
+/// the repository, SQS client, and queue URL — was set once at startup).
+/// `AppError`s surfaced by the resolvers arrive as GraphQL errors with a
+/// `code` extension, so always responds 200 per GraphQL-over-HTTP
+/// convention; see `graphql::to_gql_error`.
+///
+/// # Arguments
+///
+/// * `graphql_schema` - The GraphQL schema to execute against
+/// * `graphql_request` - The parsed GraphQL request body
+/// * `principal` - The authenticated principal performing the query/mutation
+///
+/// # Returns
+///
+/// * `Result<Response<StreamableBody>, AppError>` - The GraphQL response or an error
+async fn handle_graphql(
+    graphql_schema: &ItemSchema,
+    graphql_request: async_graphql::Request,
+    principal: auth::Principal,
+) -> Result<Response<StreamableBody>, AppError> {
+    let response = graphql_schema.execute(graphql_request.data(principal)).await;
+    let body = serde_json::to_string(&response)?;
+
+    Ok(Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(StreamableBody::buffered(body))
+        .map_err(|e| AppError::Internal(e.to_string()))?)
+}
+
 /// Helper function to create an error response
 ///
 /// # Arguments
@@ -472,23 +1138,23 @@ async fn delete_item(
 ///
 /// # Returns
 ///
-/// * `Response<Body>` - An HTTP response with the error message
-fn error_response(message: String, status_code: u16) -> Response<Body> {
+/// * `Response<StreamableBody>` - An HTTP response with the error message
+fn error_response(message: String, status_code: u16) -> Response<StreamableBody> {
     let error = ErrorResponse { message };
     let body = serde_json::to_string(&error).unwrap_or_else(|_| {
         json!({ "message": "Error serializing error response" }).to_string()
     });
-    
+
     Response::builder()
         .status(status_code)
         .header("Content-Type", "application/json")
-        .body(Body::from(body))
+        .body(StreamableBody::buffered(body))
         .unwrap_or_else(|_| {
             let fallback_body = json!({ "message": "Internal server error" }).to_string();
             Response::builder()
                 .status(500)
                 .header("Content-Type", "application/json")
-                .body(Body::from(fallback_body))
+                .body(StreamableBody::buffered(fallback_body))
                 .unwrap()
         })
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use lambda_http::{Request, Response};
+use shared::AppError;
+use crate::streaming::StreamableBody;
+
+/// Named path captures extracted from a matched route, e.g. `{"id": "42"}`
+/// for pattern `/items/{id}` against path `/items/42`
+pub type Params = HashMap<String, String>;
+
+type HandlerFuture<'a> = Pin<Box<dyn Future<Output = Result<Response<StreamableBody>, AppError>> + 'a>>;
+type Handler<'a> = Box<dyn Fn(Request, Params) -> HandlerFuture<'a> + 'a>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Param(String),
+}
+
+struct Route<'a> {
+    method: &'static str,
+    segments: Vec<Segment>,
+    handler: Handler<'a>,
+}
+
+/// A declarative router matching `METHOD /path/{param}` patterns to handlers
+///
+/// Routes are registered with [`register`](Self::register) (typically via
+/// the [`route!`](crate::route) macro) and matched with the most specific
+/// pattern winning — i.e. the one with the fewest `{param}` captures. If a
+/// path matches some registered route but none for the request's method,
+/// [`dispatch`](Self::dispatch) returns `AppError::MethodNotAllowed` listing
+/// the methods that do match, which the caller turns into a 405 with an
+/// `Allow` header.
+#[derive(Default)]
+pub struct Router<'a> {
+    routes: Vec<Route<'a>>,
+}
+
+impl<'a> Router<'a> {
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    /// Registers `handler` for requests matching `method` + `pattern`
+    ///
+    /// `pattern` segments wrapped in braces (e.g. `{id}`) capture that path
+    /// segment under the given name in the `Params` passed to `handler`.
+    pub fn register<F, Fut>(&mut self, method: &'static str, pattern: &'static str, handler: F)
+    where
+        F: Fn(Request, Params) -> Fut + 'a,
+        Fut: Future<Output = Result<Response<StreamableBody>, AppError>> + 'a,
+    {
+        self.routes.push(Route {
+            method,
+            segments: parse_pattern(pattern),
+            handler: Box::new(move |req, params| Box::pin(handler(req, params))),
+        });
+    }
+
+    /// Matches `method` + `path` against the registered routes and invokes
+    /// the winning handler with `request` and its extracted path params
+    pub async fn dispatch(&self, method: &str, path: &str, request: Request) -> Result<Response<StreamableBody>, AppError> {
+        let path_segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let mut best: Option<(&Route<'a>, Params)> = None;
+        let mut allowed_methods = Vec::new();
+
+        for route in &self.routes {
+            let Some(params) = match_segments(&route.segments, &path_segments) else {
+                continue;
+            };
+
+            if route.method == method {
+                if best.as_ref().map_or(true, |(_, best_params)| params.len() < best_params.len()) {
+                    best = Some((route, params));
+                }
+            } else {
+                allowed_methods.push(route.method);
+            }
+        }
+
+        if let Some((route, params)) = best {
+            return (route.handler)(request, params).await;
+        }
+
+        if !allowed_methods.is_empty() {
+            allowed_methods.sort_unstable();
+            allowed_methods.dedup();
+            return Err(AppError::MethodNotAllowed(allowed_methods.join(", ")));
+        }
+
+        Err(AppError::NotFound(format!("No route matches {} {}", method, path)))
+    }
+}
+
+/// Splits a registration pattern like `/items/{id}` into literal and
+/// named-capture segments
+fn parse_pattern(pattern: &str) -> Vec<Segment> {
+    pattern
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(name) => Segment::Param(name.to_string()),
+            None => Segment::Literal(segment.to_string()),
+        })
+        .collect()
+}
+
+/// Matches a compiled pattern against the actual path segments, returning
+/// the captured params on success
+fn match_segments(pattern: &[Segment], path: &[&str]) -> Option<Params> {
+    if pattern.len() != path.len() {
+        return None;
+    }
+
+    let mut params = Params::new();
+    for (segment, value) in pattern.iter().zip(path) {
+        match segment {
+            Segment::Literal(literal) if literal == value => {},
+            Segment::Param(name) => {
+                params.insert(name.clone(), value.to_string());
+            },
+            _ => return None,
+        }
+    }
+
+    Some(params)
+}
+
+/// Registers several `METHOD PATTERN => handler` pairs on a [`Router`] in one
+/// call, so `handle_request` can read as a flat table of routes
+#[macro_export]
+macro_rules! route {
+    ($router:expr, { $($method:literal $pattern:literal => $handler:expr),* $(,)? }) => {
+        $( $router.register($method, $pattern, $handler); )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pattern_splits_literals_and_params() {
+        let segments = parse_pattern("/items/{id}/attachments/{name}");
+
+        assert_eq!(
+            segments,
+            vec![
+                Segment::Literal("items".to_string()),
+                Segment::Param("id".to_string()),
+                Segment::Literal("attachments".to_string()),
+                Segment::Param("name".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn match_segments_captures_params_by_name() {
+        let pattern = parse_pattern("/items/{id}");
+        let path: Vec<&str> = "/items/42".split('/').filter(|s| !s.is_empty()).collect();
+
+        let params = match_segments(&pattern, &path).expect("path should match pattern");
+
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn match_segments_rejects_literal_mismatch() {
+        let pattern = parse_pattern("/items/{id}");
+        let path: Vec<&str> = "/widgets/42".split('/').filter(|s| !s.is_empty()).collect();
+
+        assert!(match_segments(&pattern, &path).is_none());
+    }
+
+    #[test]
+    fn match_segments_rejects_wrong_segment_count() {
+        let pattern = parse_pattern("/items/{id}");
+        let path: Vec<&str> = "/items".split('/').filter(|s| !s.is_empty()).collect();
+
+        assert!(match_segments(&pattern, &path).is_none());
+    }
+}
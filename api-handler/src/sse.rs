@@ -0,0 +1,33 @@
+use std::fmt::Write as _;
+
+/// Formats a single Server-Sent Events frame
+///
+/// `id` and `event` are per the SSE spec optional; pass `None` to omit
+/// either. `data` is written one `data:` line per line it contains, so
+/// multi-line payloads (there shouldn't be any here — everything we send is
+/// single-line JSON) still parse correctly on the client.
+pub fn format_event(id: Option<u64>, event: Option<&str>, data: &str) -> String {
+    let mut frame = String::new();
+
+    if let Some(id) = id {
+        let _ = writeln!(frame, "id: {}", id);
+    }
+    if let Some(event) = event {
+        let _ = writeln!(frame, "event: {}", event);
+    }
+    for line in data.lines() {
+        let _ = writeln!(frame, "data: {}", line);
+    }
+    frame.push('\n');
+
+    frame
+}
+
+/// Formats an SSE comment line
+///
+/// Comments are ignored by `EventSource` listeners but keep the connection
+/// from reading as idle, so we interleave them between frames on long
+/// responses and send one on their own when a poll comes back empty.
+pub fn keep_alive_comment() -> String {
+    ": keep-alive\n\n".to_string()
+}
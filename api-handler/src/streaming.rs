@@ -0,0 +1,51 @@
+use bytes::Bytes;
+use futures::Stream;
+use http_body::{Body as HttpBody, Frame};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A response body that's either a single buffered chunk or a stream of
+/// chunks emitted as they become available.
+///
+/// Running the Lambda under `lambda_http::run_with_streaming_response`
+/// requires every handler to return the same body type, but only the
+/// unfiltered `GET /items` listing (JSON or SSE) and `GET /items/events`
+/// actually have anything to stream — everything else still produces one
+/// JSON string up front. `StreamableBody::buffered` covers
+/// that common case so the rest of the handlers are unaffected; only
+/// `get_items`/`stream_item_events` use `StreamableBody::streaming`.
+pub enum StreamableBody {
+    Buffered(Option<Bytes>),
+    Streaming(Pin<Box<dyn Stream<Item = Result<Bytes, std::convert::Infallible>> + Send>>),
+}
+
+impl StreamableBody {
+    pub fn buffered(bytes: impl Into<Bytes>) -> Self {
+        Self::Buffered(Some(bytes.into()))
+    }
+
+    pub fn streaming<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, std::convert::Infallible>> + Send + 'static,
+    {
+        Self::Streaming(Box::pin(stream))
+    }
+}
+
+impl HttpBody for StreamableBody {
+    type Data = Bytes;
+    type Error = std::convert::Infallible;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        match self.get_mut() {
+            Self::Buffered(bytes) => Poll::Ready(bytes.take().map(|b| Ok(Frame::data(b)))),
+            Self::Streaming(stream) => stream
+                .as_mut()
+                .poll_next(cx)
+                .map(|opt| opt.map(|res| res.map(Frame::data))),
+        }
+    }
+}
@@ -81,6 +81,8 @@ mod tests {
             created_at: DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
                 .unwrap()
                 .with_timezone(&Utc),
+            classification: "INTERNAL".to_string(),
+            attachments: Vec::new(),
         }
     }
 
@@ -1,7 +1,10 @@
 use lambda_runtime::{service_fn, Error, LambdaEvent};
 use aws_lambda_events::sqs::{SqsEvent, SqsMessage};
-use tracing::{info, error};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::{info, error, warn};
 use shared::{
+    archive::S3Archiver,
     models::{ItemEvent, ItemEventType},
     repository::DynamoDbRepository,
     config::AppConfig,
@@ -9,10 +12,34 @@ use shared::{
 };
 use std::time::Duration;
 
+/// How long a claimed event ID is retained in the dedup table before it
+/// expires and could (in principle) be reprocessed
+const DEDUP_TTL_SECS: i64 = 24 * 60 * 60;
+
 /// Test module for unit testing the event processor
 #[cfg(test)]
 mod tests;
 
+/// A single SQS message that failed processing, identified by its `messageId`
+///
+/// This is the shape SQS expects for partial batch failure reporting.
+#[derive(Debug, Serialize)]
+struct BatchItemFailure {
+    #[serde(rename = "itemIdentifier")]
+    item_identifier: String,
+}
+
+/// Response returned from `handle_event` when the Lambda has
+/// `ReportBatchItemFailures` enabled on its SQS event source mapping
+///
+/// Only the message IDs listed here are redelivered by SQS; everything
+/// else in the batch is treated as successfully processed.
+#[derive(Debug, Serialize, Default)]
+struct SqsBatchResponse {
+    #[serde(rename = "batchItemFailures")]
+    batch_item_failures: Vec<BatchItemFailure>,
+}
+
 /// Main entry point for the event processor Lambda function
 ///
 /// This function initializes the AWS SDK, sets up logging, and starts the Lambda runtime.
@@ -33,10 +60,11 @@ async fn main() -> Result<(), Error> {
     // Initialize AWS SDK clients
     let aws_config = aws_config::load_from_env().await;
     let repo = DynamoDbRepository::new(&aws_config);
+    let archiver = S3Archiver::new(&aws_config, config.archive_bucket.clone());
 
     // Run the Lambda service with our event handler
     lambda_runtime::run(service_fn(|event: LambdaEvent<SqsEvent>| {
-        handle_event(event, &repo)
+        handle_event(event, &repo, &archiver)
     })).await?;
 
     Ok(())
@@ -45,30 +73,63 @@ async fn main() -> Result<(), Error> {
 /// Main event handler for the SQS Lambda
 ///
 /// This function processes SQS events, which may contain multiple messages.
-/// Each message is processed individually.
+/// Each message is processed individually, and messages that fail are
+/// reported back to SQS by `messageId` so only they get redelivered
+/// (requires `ReportBatchItemFailures` on the event source mapping).
 ///
 /// # Arguments
 ///
 /// * `event` - The SQS event from Lambda
 /// * `repo` - The DynamoDB repository for data access
+/// * `archiver` - The S3 archiver for durably retaining the event stream
 ///
 /// # Returns
 ///
-/// * `Result<(), Error>` - Success or an error
+/// * `Result<SqsBatchResponse, Error>` - The set of failed message IDs, or a hard error
 async fn handle_event(
     event: LambdaEvent<SqsEvent>,
     repo: &DynamoDbRepository,
-) -> Result<(), Error> {
+    archiver: &S3Archiver,
+) -> Result<SqsBatchResponse, Error> {
     let (event, _context) = event.into_parts();
-    
+
     info!("Processing {} SQS messages", event.records.len());
-    
-    // Process each SQS message in the batch
+
+    // Process each message independently and collect only the failures, so a
+    // single bad message doesn't cause SQS to redeliver the whole batch
+    let mut batch_item_failures = Vec::new();
+    let mut processed_events = Vec::new();
+
     for record in event.records {
-        process_sqs_message(record, repo).await?;
+        let message_id = record.message_id.clone();
+
+        match process_sqs_message(record, repo).await {
+            Ok(Some(item_event)) => processed_events.push(item_event),
+            Ok(None) => {}, // duplicate, already claimed by an earlier delivery
+            Err(err) => {
+                match message_id {
+                    Some(id) => {
+                        error!("Failed to process message {}: {:?}", id, err);
+                        batch_item_failures.push(BatchItemFailure { item_identifier: id });
+                    },
+                    None => {
+                        // No message ID means we can't report this as a partial
+                        // failure, so treat it as a hard failure for the whole batch
+                        error!("Failed to process message with no message ID: {:?}", err);
+                        return Err(err);
+                    },
+                }
+            },
+        }
     }
-    
-    Ok(())
+
+    // Archive the batch's successfully processed events in one S3 put, so the
+    // full event stream is durably retained for replay and analytics
+    if let Err(err) = archiver.append_events(&processed_events).await {
+        error!("Failed to archive {} event(s): {:?}", processed_events.len(), err);
+    }
+
+    Ok(SqsBatchResponse { batch_item_failures })
 }
 
 /// Process a single SQS message
@@ -83,11 +144,12 @@ async fn handle_event(
 ///
 /// # Returns
 ///
-/// * `Result<(), Error>` - Success or an error
+/// * `Result<Option<ItemEvent>, Error>` - The processed event, `None` if it was
+///   a duplicate that had already been claimed, or an error
 async fn process_sqs_message(
     message: SqsMessage,
     repo: &DynamoDbRepository,
-) -> Result<(), Error> {
+) -> Result<Option<ItemEvent>, Error> {
     // Extract the message body
     let body = message.body.as_deref().ok_or_else(|| {
         error!("SQS message has no body");
@@ -98,7 +160,19 @@ async fn process_sqs_message(
     
     // Parse the event from JSON
     let item_event: ItemEvent = serde_json::from_str(body)?;
-    
+
+    // Derive a stable event ID and claim it, so a redelivered message (SQS
+    // only guarantees at-least-once delivery) isn't processed twice
+    let event_id = stable_event_id(&item_event);
+    match repo.try_claim_event(&event_id, DEDUP_TTL_SECS).await {
+        Ok(()) => {},
+        Err(AppError::Duplicate(_)) => {
+            warn!("Skipping already-processed event {} for item {}", event_id, item_event.item.id);
+            return Ok(None);
+        },
+        Err(err) => return Err(err.into()),
+    }
+
     // Process based on event type
     match item_event.event_type {
         ItemEventType::Created => {
@@ -119,9 +193,28 @@ async fn process_sqs_message(
             // For example, clean up related resources, update analytics, etc.
             tokio::time::sleep(Duration::from_millis(100)).await; // Simulate processing
         },
+        ItemEventType::AttachmentAdded => {
+            info!("Attachment added event for item ID: {}", item_event.item.id);
+            // In a real application, you might scan the attachment for malware,
+            // generate a thumbnail, update search indices, etc.
+            tokio::time::sleep(Duration::from_millis(100)).await; // Simulate processing
+        },
     }
     
     info!("Successfully processed event for item ID: {}", item_event.item.id);
-    
-    Ok(())
+
+    Ok(Some(item_event))
+}
+
+/// Derives a stable ID for an `ItemEvent` so the same logical event always
+/// claims the same dedup row, regardless of how many times SQS redelivers it
+///
+/// Hashes `event_type` + `item.id` + `timestamp`, which together uniquely
+/// identify the event that was originally published.
+fn stable_event_id(event: &ItemEvent) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{:?}", event.event_type).as_bytes());
+    hasher.update(event.item.id.as_bytes());
+    hasher.update(event.timestamp.to_rfc3339().as_bytes());
+    format!("{:x}", hasher.finalize())
 } 
\ No newline at end of file
@@ -0,0 +1,63 @@
+use aws_sdk_s3::Client;
+use tracing::info;
+use uuid::Uuid;
+use crate::models::ItemEvent;
+use crate::error::AppError;
+
+/// Archives `ItemEvent`s to S3 for durable retention, replay, and analytics
+///
+/// Events are written as newline-delimited JSON, one object per call to
+/// [`append_events`](Self::append_events), keyed with Hive-style date
+/// partitions so the prefix layout is directly queryable by table formats
+/// (Athena, Glue, etc.) and S3 Select.
+pub struct S3Archiver {
+    client: Client,
+    bucket: String,
+}
+
+impl S3Archiver {
+    pub fn new(config: &aws_config::SdkConfig, bucket: String) -> Self {
+        let client = Client::new(config);
+
+        Self { client, bucket }
+    }
+
+    /// Writes `events` to a single new object under
+    /// `year=YYYY/month=MM/day=DD/{uuid}.json`, partitioned by the
+    /// timestamp of the first event in the batch.
+    ///
+    /// Does nothing if `events` is empty.
+    pub async fn append_events(&self, events: &[ItemEvent]) -> Result<(), AppError> {
+        let Some(first) = events.first() else {
+            return Ok(());
+        };
+
+        let key = format!(
+            "year={:04}/month={:02}/day={:02}/{}.json",
+            first.timestamp.format("%Y"),
+            first.timestamp.format("%m"),
+            first.timestamp.format("%d"),
+            Uuid::new_v4()
+        );
+
+        let mut body = Vec::new();
+        for event in events {
+            serde_json::to_writer(&mut body, event)?;
+            body.push(b'\n');
+        }
+
+        info!("Archiving {} event(s) to s3://{}/{}", events.len(), self.bucket, key);
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .body(body.into())
+            .content_type("application/x-ndjson")
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to archive events to S3: {}", e)))?;
+
+        Ok(())
+    }
+}
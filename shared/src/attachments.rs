@@ -0,0 +1,75 @@
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::Client;
+use std::time::Duration;
+use crate::error::AppError;
+
+/// Issues presigned S3 URLs for uploading and downloading item attachments
+///
+/// The attachment's bytes never pass through the Lambda: clients `PUT` to
+/// the upload URL and `GET` from the download URL directly against S3,
+/// which sidesteps the API Gateway/Lambda payload size limit entirely.
+pub struct AttachmentStore {
+    client: Client,
+    bucket: String,
+}
+
+impl AttachmentStore {
+    pub fn new(config: &aws_config::SdkConfig, bucket: String) -> Self {
+        let client = Client::new(config);
+
+        Self { client, bucket }
+    }
+
+    /// Builds the S3 key an item's attachment is stored under
+    ///
+    /// `name` is client-supplied, so it's rejected outright if it could
+    /// escape the `attachments/{item_id}/` prefix (a `/` or a `..` segment)
+    /// rather than sanitized, since silently rewriting it would let two
+    /// different uploads collide under the same key without either caller
+    /// knowing.
+    pub fn key_for(item_id: &str, name: &str) -> Result<String, AppError> {
+        if name.is_empty() || name.contains('/') || name.contains('\\') || name.split('/').any(|segment| segment == "..") {
+            return Err(AppError::Validation(format!(
+                "Invalid attachment name: {}",
+                name
+            )));
+        }
+
+        Ok(format!("attachments/{}/{}", item_id, name))
+    }
+
+    /// Returns a presigned URL the client can `PUT` the attachment's bytes to,
+    /// valid for `expires_in`
+    pub async fn presign_upload(&self, key: &str, content_type: &str, expires_in: Duration) -> Result<String, AppError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(|e| AppError::Internal(format!("Invalid presigning expiry: {}", e)))?;
+
+        let presigned = self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .presigned(presigning_config)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to presign attachment upload URL: {}", e)))?;
+
+        Ok(presigned.uri().to_string())
+    }
+
+    /// Returns a presigned URL the client can `GET` the attachment's bytes
+    /// from, valid for `expires_in`
+    pub async fn presign_download(&self, key: &str, expires_in: Duration) -> Result<String, AppError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(|e| AppError::Internal(format!("Invalid presigning expiry: {}", e)))?;
+
+        let presigned = self.client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .presigned(presigning_config)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to presign attachment download URL: {}", e)))?;
+
+        Ok(presigned.uri().to_string())
+    }
+}
@@ -6,6 +6,9 @@ pub struct AppConfig {
     pub environment: String,
     pub log_level: String,
     pub table_name: String,
+    pub archive_bucket: String,
+    pub attachments_bucket: String,
+    pub attachment_url_expiry_secs: u64,
 }
 
 impl AppConfig {
@@ -13,11 +16,20 @@ impl AppConfig {
         let environment = env::var("ENVIRONMENT").unwrap_or_else(|_| "dev".to_string());
         let log_level = env::var("LOG_LEVEL").unwrap_or_else(|_| "INFO".to_string());
         let table_name = env::var("TABLE_NAME").unwrap_or_else(|_| "Items".to_string());
-        
+        let archive_bucket = env::var("ARCHIVE_BUCKET").unwrap_or_else(|_| "item-events-archive".to_string());
+        let attachments_bucket = env::var("ATTACHMENTS_BUCKET").unwrap_or_else(|_| "item-attachments".to_string());
+        let attachment_url_expiry_secs = env::var("ATTACHMENT_URL_EXPIRY_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(900);
+
         let config = Self {
             environment,
             log_level,
             table_name,
+            archive_bucket,
+            attachments_bucket,
+            attachment_url_expiry_secs,
         };
         
         info!("Loaded configuration: {:?}", config);
@@ -16,7 +16,16 @@ pub enum AppError {
     
     #[error("Not found: {0}")]
     NotFound(String),
-    
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Duplicate event, already processed: {0}")]
+    Duplicate(String),
+
+    #[error("Method not allowed, supported methods: {0}")]
+    MethodNotAllowed(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -7,11 +7,17 @@
 ///
 /// * `models` - Data models for items and events
 /// * `repository` - DynamoDB repository for data access
+/// * `archive` - S3 archival of events for analytics/replay
+/// * `retry` - Exponential backoff retry helper for throttled DynamoDB calls
+/// * `attachments` - Presigned S3 URLs for item attachment upload/download
 /// * `error` - Error handling
 /// * `config` - Configuration management
 
 pub mod models;
 pub mod repository;
+pub mod archive;
+pub mod retry;
+pub mod attachments;
 pub mod error;
 pub mod config;
 
@@ -30,6 +30,34 @@ pub struct Item {
     /// Options: PUBLIC, INTERNAL, CONFIDENTIAL, RESTRICTED
     #[serde(default = "default_classification")]
     pub classification: String,
+
+    /// Files attached to this item, stored in S3 and uploaded/downloaded via
+    /// presigned URLs (see `shared::attachments::AttachmentStore`)
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+/// Metadata about a file attached to an [`Item`] and stored in S3
+///
+/// The attachment's bytes never pass through the Lambda itself; clients
+/// `PUT`/`GET` them directly against S3 using presigned URLs.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Attachment {
+    /// File name, also the last path segment of
+    /// `GET /items/{id}/attachments/{name}`
+    pub name: String,
+
+    /// S3 object key the attachment's bytes are stored under
+    pub s3_key: String,
+
+    /// MIME type of the attachment
+    pub content_type: String,
+
+    /// Size of the attachment in bytes
+    pub size_bytes: i64,
+
+    /// SHA-256 hash of the attachment's bytes, hex-encoded
+    pub sha256: String,
 }
 
 /// Generates a new UUID string for item IDs
@@ -95,6 +123,16 @@ pub enum ItemEventType {
     
     /// Item was deleted
     Deleted,
+
+    /// An attachment was added to an item
+    AttachmentAdded,
+}
+
+impl std::fmt::Display for ItemEventType {
+    /// Renders the variant name, e.g. `Created`, for use as an SSE `event:` field
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
 
 /// Audit record for tracking changes to items
@@ -129,11 +167,34 @@ pub struct AuditRecord {
     
     /// ID of the request that triggered the action
     pub request_id: String,
-    
-    /// Hash of the original request for non-repudiation
+
+    /// `hash` of the previous audit record for this resource, or `None` for
+    /// the first record in its chain
+    pub prev_hash: Option<String>,
+
+    /// SHA-256 of `prev_hash || event_id || action || resource_id ||
+    /// timestamp || new_state`, hex-encoded — this record's link in the
+    /// tamper-evident audit chain
     pub hash: Option<String>,
 }
 
+/// A single operation within a `POST /items/batch` request
+///
+/// Batches of these are applied by `DynamoDbRepository::batch_write_items`
+/// in transactions of at most 25 operations each.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum ItemWrite {
+    /// Create the given item
+    Create(Item),
+
+    /// Delete the item with this ID
+    Delete {
+        /// ID of the item to delete
+        id: String,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +210,7 @@ mod tests {
                 .unwrap()
                 .with_timezone(&Utc),
             classification: "INTERNAL".to_string(),
+            attachments: Vec::new(),
         };
 
         let json = serde_json::to_string(&item).unwrap();
@@ -157,7 +219,8 @@ mod tests {
             "name": "Test Item",
             "description": "Test Description",
             "created_at": "2023-01-01T00:00:00Z",
-            "classification": "INTERNAL"
+            "classification": "INTERNAL",
+            "attachments": []
         });
 
         assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), expected);
@@ -214,6 +277,8 @@ mod tests {
             created_at: DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
                 .unwrap()
                 .with_timezone(&Utc),
+            classification: "INTERNAL".to_string(),
+            attachments: Vec::new(),
         };
 
         let event = ItemEvent {
@@ -231,7 +296,9 @@ mod tests {
                 "id": "test-id",
                 "name": "Test Item",
                 "description": "Test Description",
-                "created_at": "2023-01-01T00:00:00Z"
+                "created_at": "2023-01-01T00:00:00Z",
+                "classification": "INTERNAL",
+                "attachments": []
             },
             "timestamp": "2023-01-01T00:00:00Z"
         });
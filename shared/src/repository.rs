@@ -1,13 +1,19 @@
 use aws_sdk_dynamodb::{Client, Error};
-use aws_sdk_dynamodb::model::AttributeValue;
+use aws_sdk_dynamodb::model::{AttributeValue, Put, TransactWriteItem};
+use async_stream::try_stream;
+use base64::Engine as _;
+use futures::Stream;
 use std::collections::HashMap;
 use tracing::{info, error};
-use crate::models::Item;
+use crate::models::{AuditRecord, Item, ItemWrite};
 use crate::error::AppError;
+use crate::retry::{self, ExponentialBackoffConfig};
 
 pub struct DynamoDbRepository {
     client: Client,
     table_name: String,
+    audit_table_name: String,
+    dedup_table_name: String,
 }
 
 impl DynamoDbRepository {
@@ -15,112 +21,673 @@ impl DynamoDbRepository {
         let client = Client::new(config);
         let table_name = std::env::var("TABLE_NAME")
             .unwrap_or_else(|_| "Items".to_string());
-        
-        Self { client, table_name }
+        let audit_table_name = std::env::var("AUDIT_TABLE_NAME")
+            .unwrap_or_else(|_| "AuditRecords".to_string());
+        let dedup_table_name = std::env::var("DEDUP_TABLE_NAME")
+            .unwrap_or_else(|_| "ProcessedEvents".to_string());
+
+        Self { client, table_name, audit_table_name, dedup_table_name }
     }
-    
-    pub async fn create_item(&self, item: &Item) -> Result<(), Error> {
+
+    /// Builds the DynamoDB attribute map for an item's base fields
+    ///
+    /// This includes `classification` and `classification_sort_key`, the
+    /// partition/sort attributes backing the `ByClassification` GSI used by
+    /// [`list_items_by_classification`](Self::list_items_by_classification).
+    fn item_attributes(item: &Item) -> HashMap<String, AttributeValue> {
         let mut item_attributes = HashMap::new();
         item_attributes.insert("id".to_string(), AttributeValue::S(item.id.clone()));
         item_attributes.insert("name".to_string(), AttributeValue::S(item.name.clone()));
-        
+
         if let Some(desc) = &item.description {
             item_attributes.insert("description".to_string(), AttributeValue::S(desc.clone()));
         }
-        
-        item_attributes.insert("created_at".to_string(), 
+
+        item_attributes.insert("created_at".to_string(),
             AttributeValue::S(item.created_at.to_rfc3339()));
-        
+        item_attributes.insert("classification".to_string(),
+            AttributeValue::S(item.classification.clone()));
+        item_attributes.insert(
+            "classification_sort_key".to_string(),
+            AttributeValue::S(format!("{}#{}", item.created_at.to_rfc3339(), item.id)),
+        );
+        item_attributes.insert(
+            "attachments".to_string(),
+            AttributeValue::L(item.attachments.iter().map(Self::attachment_attributes).collect()),
+        );
+
+        item_attributes
+    }
+
+    /// Parses an `Item` back out of a DynamoDB attribute map, filling in
+    /// sensible defaults for any attribute an older item predates.
+    fn item_from_attributes(attributes: &HashMap<String, AttributeValue>) -> Option<Item> {
+        let id = attributes.get("id")?.as_s().ok()?.clone();
+        let name = attributes.get("name")?.as_s().ok()?.clone();
+        let description = attributes.get("description").and_then(|v| v.as_s().ok()).cloned();
+
+        let created_at = attributes.get("created_at")
+            .and_then(|v| v.as_s().ok())
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+            .unwrap_or_else(chrono::Utc::now);
+
+        let classification = attributes.get("classification")
+            .and_then(|v| v.as_s().ok())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "INTERNAL".to_string());
+
+        let attachments = attributes.get("attachments")
+            .and_then(|v| v.as_l().ok())
+            .map(|list| list.iter().filter_map(Self::attachment_from_attributes).collect())
+            .unwrap_or_default();
+
+        Some(Item { id, name, description, created_at, classification, attachments })
+    }
+
+    /// Builds the DynamoDB map (`M`) attribute for a single `Attachment`,
+    /// used both in [`item_attributes`](Self::item_attributes)' `attachments`
+    /// list and as the `:new` value [`add_attachment`](Self::add_attachment)
+    /// appends via `list_append` — a native DynamoDB list rather than a
+    /// JSON-encoded string, so that append can be done atomically server-side.
+    fn attachment_attributes(attachment: &crate::models::Attachment) -> AttributeValue {
+        let mut attachment_attributes = HashMap::new();
+        attachment_attributes.insert("name".to_string(), AttributeValue::S(attachment.name.clone()));
+        attachment_attributes.insert("s3_key".to_string(), AttributeValue::S(attachment.s3_key.clone()));
+        attachment_attributes.insert("content_type".to_string(), AttributeValue::S(attachment.content_type.clone()));
+        attachment_attributes.insert("size_bytes".to_string(), AttributeValue::N(attachment.size_bytes.to_string()));
+        attachment_attributes.insert("sha256".to_string(), AttributeValue::S(attachment.sha256.clone()));
+
+        AttributeValue::M(attachment_attributes)
+    }
+
+    /// Parses an `Attachment` back out of its DynamoDB map (`M`) attribute
+    fn attachment_from_attributes(value: &AttributeValue) -> Option<crate::models::Attachment> {
+        let attachment_attributes = value.as_m().ok()?;
+
+        Some(crate::models::Attachment {
+            name: attachment_attributes.get("name")?.as_s().ok()?.clone(),
+            s3_key: attachment_attributes.get("s3_key")?.as_s().ok()?.clone(),
+            content_type: attachment_attributes.get("content_type")?.as_s().ok()?.clone(),
+            size_bytes: attachment_attributes.get("size_bytes")?.as_n().ok()?.parse().ok()?,
+            sha256: attachment_attributes.get("sha256")?.as_s().ok()?.clone(),
+        })
+    }
+
+    /// Builds the DynamoDB attribute map for an `AuditRecord`
+    ///
+    /// Partition key is `resource_id`; sort key is
+    /// `{timestamp_rfc3339}#{action}`, so a resource's audit trail is stored
+    /// and queryable in chronological order without a separate GSI.
+    fn audit_attributes(audit: &AuditRecord) -> HashMap<String, AttributeValue> {
+        let mut audit_attributes = HashMap::new();
+        audit_attributes.insert("resource_id".to_string(), AttributeValue::S(audit.resource_id.clone()));
+        audit_attributes.insert(
+            "sort_key".to_string(),
+            AttributeValue::S(format!("{}#{}", audit.timestamp.to_rfc3339(), audit.action)),
+        );
+        audit_attributes.insert("event_id".to_string(), AttributeValue::S(audit.event_id.clone()));
+        audit_attributes.insert("user_id".to_string(), AttributeValue::S(audit.user_id.clone()));
+        audit_attributes.insert("action".to_string(), AttributeValue::S(audit.action.clone()));
+        audit_attributes.insert("resource_type".to_string(), AttributeValue::S(audit.resource_type.clone()));
+        audit_attributes.insert("timestamp".to_string(), AttributeValue::S(audit.timestamp.to_rfc3339()));
+        audit_attributes.insert("request_id".to_string(), AttributeValue::S(audit.request_id.clone()));
+        if let Some(prev) = &audit.previous_state {
+            audit_attributes.insert("previous_state".to_string(), AttributeValue::S(prev.clone()));
+        }
+        if let Some(new_state) = &audit.new_state {
+            audit_attributes.insert("new_state".to_string(), AttributeValue::S(new_state.clone()));
+        }
+        if let Some(prev_hash) = &audit.prev_hash {
+            audit_attributes.insert("prev_hash".to_string(), AttributeValue::S(prev_hash.clone()));
+        }
+        if let Some(hash) = &audit.hash {
+            audit_attributes.insert("hash".to_string(), AttributeValue::S(hash.clone()));
+        }
+
+        audit_attributes
+    }
+
+    /// Parses an `AuditRecord` back out of a DynamoDB attribute map
+    fn audit_from_attributes(attributes: &HashMap<String, AttributeValue>) -> Option<AuditRecord> {
+        let resource_id = attributes.get("resource_id")?.as_s().ok()?.clone();
+        let event_id = attributes.get("event_id")?.as_s().ok()?.clone();
+        let user_id = attributes.get("user_id")?.as_s().ok()?.clone();
+        let action = attributes.get("action")?.as_s().ok()?.clone();
+        let resource_type = attributes.get("resource_type")?.as_s().ok()?.clone();
+        let request_id = attributes.get("request_id")?.as_s().ok()?.clone();
+
+        let timestamp = attributes.get("timestamp")
+            .and_then(|v| v.as_s().ok())
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&chrono::Utc))?;
+
+        let previous_state = attributes.get("previous_state").and_then(|v| v.as_s().ok()).cloned();
+        let new_state = attributes.get("new_state").and_then(|v| v.as_s().ok()).cloned();
+        let prev_hash = attributes.get("prev_hash").and_then(|v| v.as_s().ok()).cloned();
+        let hash = attributes.get("hash").and_then(|v| v.as_s().ok()).cloned();
+
+        Some(AuditRecord {
+            event_id,
+            user_id,
+            action,
+            resource_id,
+            resource_type,
+            timestamp,
+            previous_state,
+            new_state,
+            request_id,
+            prev_hash,
+            hash,
+        })
+    }
+
+    /// Creates a new item, retrying transparently on throttling
+    ///
+    /// The write is conditional on `attribute_not_exists(id)`, so a retried
+    /// `POST /items` with the same ID can't silently overwrite the item that
+    /// was already created; it instead fails with `AppError::Conflict`, the
+    /// same mapping `batch_write_items`/`create_item_with_audit` use for the
+    /// equivalent `TransactionCanceledException`.
+    pub async fn create_item(&self, item: &Item) -> Result<(), AppError> {
         info!("Creating item with ID: {}", item.id);
-        
-        self.client
-            .put_item()
-            .table_name(&self.table_name)
-            .set_item(Some(item_attributes))
-            .send()
+
+        let backoff = ExponentialBackoffConfig::default();
+
+        retry::retry_on_throttle(&backoff, || async {
+            self.client
+                .put_item()
+                .table_name(&self.table_name)
+                .set_item(Some(Self::item_attributes(item)))
+                .condition_expression("attribute_not_exists(id)")
+                .send()
+                .await
+                .map_err(|e| match Error::from(e) {
+                    Error::ConditionalCheckFailedException(_) => AppError::Conflict(format!(
+                        "Item with ID {} already exists",
+                        item.id
+                    )),
+                    other => AppError::DynamoDb(other),
+                })
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Applies a batch of create/delete operations, chunking into DynamoDB
+    /// transactions of at most 25 items (the `TransactWriteItems` limit) and
+    /// retrying each chunk transparently on throttling.
+    ///
+    /// Each chunk is atomic, but the batch as a whole is not once it exceeds
+    /// 25 operations: chunks are written in order, and if one fails, the
+    /// chunks before it stay applied and the ones after it are not attempted.
+    pub async fn batch_write_items(&self, operations: &[ItemWrite]) -> Result<(), AppError> {
+        let backoff = ExponentialBackoffConfig::default();
+
+        for chunk in operations.chunks(25) {
+            let transact_items: Vec<TransactWriteItem> = chunk
+                .iter()
+                .map(|op| match op {
+                    ItemWrite::Create(item) => TransactWriteItem::builder()
+                        .put(
+                            Put::builder()
+                                .table_name(&self.table_name)
+                                .set_item(Some(Self::item_attributes(item)))
+                                .condition_expression("attribute_not_exists(id)")
+                                .build(),
+                        )
+                        .build(),
+                    ItemWrite::Delete { id } => TransactWriteItem::builder()
+                        .delete(
+                            aws_sdk_dynamodb::model::Delete::builder()
+                                .table_name(&self.table_name)
+                                .key("id", AttributeValue::S(id.clone()))
+                                .build(),
+                        )
+                        .build(),
+                })
+                .collect();
+
+            info!("Writing a batch of {} item operation(s) in one transaction", transact_items.len());
+
+            retry::retry_on_throttle(&backoff, || async {
+                self.client
+                    .transact_write_items()
+                    .set_transact_items(Some(transact_items.clone()))
+                    .send()
+                    .await
+                    .map_err(|e| match Error::from(e) {
+                        Error::TransactionCanceledException(details) => AppError::Conflict(format!(
+                            "Batch write was cancelled: {}",
+                            details.message().unwrap_or("conflicting write")
+                        )),
+                        other => AppError::DynamoDb(other),
+                    })
+            })
             .await?;
-            
+        }
+
         Ok(())
     }
-    
+
+    /// Writes a new item together with the next link in its audit chain
+    /// (`audit`, already built by the caller) in a single DynamoDB
+    /// transaction, so an item can never land in the table without a
+    /// corresponding audit entry surviving a crash between the two writes.
+    ///
+    /// The item write is conditional on `attribute_not_exists(id)`, same as
+    /// [`create_item`](Self::create_item), so a duplicate ID fails the whole
+    /// transaction rather than silently overwriting the existing item.
+    pub async fn create_item_with_audit(&self, item: &Item, audit: &AuditRecord) -> Result<(), AppError> {
+        let item_put = Put::builder()
+            .table_name(&self.table_name)
+            .set_item(Some(Self::item_attributes(item)))
+            .condition_expression("attribute_not_exists(id)")
+            .build();
+        let audit_put = Put::builder()
+            .table_name(&self.audit_table_name)
+            .set_item(Some(Self::audit_attributes(audit)))
+            .build();
+
+        info!("Writing item {} and its audit record in one transaction", item.id);
+
+        let backoff = ExponentialBackoffConfig::default();
+
+        retry::retry_on_throttle(&backoff, || async {
+            self.client
+                .transact_write_items()
+                .transact_items(TransactWriteItem::builder().put(item_put.clone()).build())
+                .transact_items(TransactWriteItem::builder().put(audit_put.clone()).build())
+                .send()
+                .await
+                .map_err(|e| match Error::from(e) {
+                    Error::TransactionCanceledException(details) => AppError::Conflict(format!(
+                        "Create transaction for item {} was cancelled: {}",
+                        item.id,
+                        details.message().unwrap_or("conflicting write")
+                    )),
+                    other => AppError::DynamoDb(other),
+                })
+        })
+        .await
+    }
+
+    /// Deletes an item together with the next link in its audit chain in a
+    /// single DynamoDB transaction, the delete-path counterpart of
+    /// [`create_item_with_audit`](Self::create_item_with_audit).
+    pub async fn delete_item_with_audit(&self, id: &str, audit: &AuditRecord) -> Result<(), AppError> {
+        let item_delete = aws_sdk_dynamodb::model::Delete::builder()
+            .table_name(&self.table_name)
+            .key("id", AttributeValue::S(id.to_string()))
+            .build();
+        let audit_put = Put::builder()
+            .table_name(&self.audit_table_name)
+            .set_item(Some(Self::audit_attributes(audit)))
+            .build();
+
+        info!("Deleting item {} and writing its audit record in one transaction", id);
+
+        let backoff = ExponentialBackoffConfig::default();
+
+        retry::retry_on_throttle(&backoff, || async {
+            self.client
+                .transact_write_items()
+                .transact_items(TransactWriteItem::builder().delete(item_delete.clone()).build())
+                .transact_items(TransactWriteItem::builder().put(audit_put.clone()).build())
+                .send()
+                .await
+                .map_err(|e| match Error::from(e) {
+                    Error::TransactionCanceledException(details) => AppError::Conflict(format!(
+                        "Delete transaction for item {} was cancelled: {}",
+                        id,
+                        details.message().unwrap_or("conflicting write")
+                    )),
+                    other => AppError::DynamoDb(other),
+                })
+        })
+        .await
+    }
+
+
     pub async fn get_item(&self, id: &str) -> Result<Option<Item>, Error> {
         info!("Getting item with ID: {}", id);
-        
+
         let response = self.client
             .get_item()
             .table_name(&self.table_name)
             .key("id", AttributeValue::S(id.to_string()))
             .send()
             .await?;
-            
-        if let Some(item) = response.item {
-            let id = item.get("id").and_then(|v| v.as_s().ok()).unwrap_or_default().to_string();
-            let name = item.get("name").and_then(|v| v.as_s().ok()).unwrap_or_default().to_string();
-            let description = item.get("description").and_then(|v| v.as_s().ok()).map(|s| s.to_string());
-            
-            let created_at = item.get("created_at")
-                .and_then(|v| v.as_s().ok())
-                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .unwrap_or_else(chrono::Utc::now);
-            
-            Ok(Some(Item {
-                id,
-                name,
-                description,
-                created_at,
-            }))
-        } else {
-            Ok(None)
-        }
+
+        Ok(response.item.as_ref().and_then(Self::item_from_attributes))
     }
-    
+
     pub async fn list_items(&self) -> Result<Vec<Item>, Error> {
         info!("Listing all items");
-        
+
         let response = self.client
             .scan()
             .table_name(&self.table_name)
             .send()
             .await?;
-            
+
         let items = response.items().unwrap_or_default();
-        
-        let result: Vec<Item> = items
-            .iter()
-            .filter_map(|item| {
-                let id = item.get("id")?.as_s().ok()?;
-                let name = item.get("name")?.as_s().ok()?;
-                let description = item.get("description").and_then(|v| v.as_s().ok()).cloned();
-                
-                let created_at = item.get("created_at")
-                    .and_then(|v| v.as_s().ok())
-                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                    .map(|dt| dt.with_timezone(&chrono::Utc))
-                    .unwrap_or_else(chrono::Utc::now);
-                
-                Some(Item {
-                    id: id.clone(),
-                    name: name.clone(),
-                    description,
-                    created_at,
-                })
-            })
-            .collect();
-            
+
+        let result: Vec<Item> = items.iter().filter_map(Self::item_from_attributes).collect();
+
         Ok(result)
     }
-    
+
+    /// Scans the table one page at a time, yielding items as each page comes
+    /// back instead of buffering the whole table before returning anything.
+    ///
+    /// This is what backs the streaming `GET /items` response: the API
+    /// handler can start writing items to the client as soon as the first
+    /// page arrives rather than waiting on a full table scan.
+    pub fn list_items_stream(&self, page_size: i32) -> impl Stream<Item = Result<Item, Error>> + '_ {
+        try_stream! {
+            let mut start_key: Option<HashMap<String, AttributeValue>> = None;
+
+            loop {
+                let response = self.client
+                    .scan()
+                    .table_name(&self.table_name)
+                    .limit(page_size)
+                    .set_exclusive_start_key(start_key.clone())
+                    .send()
+                    .await?;
+
+                for attributes in response.items().unwrap_or_default() {
+                    if let Some(item) = Self::item_from_attributes(attributes) {
+                        yield item;
+                    }
+                }
+
+                start_key = response.last_evaluated_key().cloned();
+                if start_key.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Queries items newest-first within a single classification, using the
+    /// `ByClassification` GSI (partition: `classification`, sort:
+    /// `classification_sort_key` = `{created_at_rfc3339}#{id}`) instead of a
+    /// full-table scan.
+    ///
+    /// Returns the page of items plus the `LastEvaluatedKey` to pass back in
+    /// as `start_key` for the next page, or `None` once there are no more
+    /// pages.
+    pub async fn list_items_by_classification(
+        &self,
+        classification: &str,
+        limit: i32,
+        start_key: Option<HashMap<String, AttributeValue>>,
+    ) -> Result<(Vec<Item>, Option<HashMap<String, AttributeValue>>), Error> {
+        info!("Listing items with classification: {}", classification);
+
+        let response = self.client
+            .query()
+            .table_name(&self.table_name)
+            .index_name("ByClassification")
+            .key_condition_expression("classification = :classification")
+            .expression_attribute_values(":classification", AttributeValue::S(classification.to_string()))
+            .scan_index_forward(false)
+            .limit(limit)
+            .set_exclusive_start_key(start_key)
+            .send()
+            .await?;
+
+        let items = response.items().unwrap_or_default();
+        let result: Vec<Item> = items.iter().filter_map(Self::item_from_attributes).collect();
+
+        Ok((result, response.last_evaluated_key().cloned()))
+    }
+
+    /// Encodes a `list_items_by_classification` page's `LastEvaluatedKey`
+    /// into an opaque cursor string that's safe to hand back to API clients.
+    ///
+    /// The `ByClassification` GSI's key only ever contains string-valued
+    /// attributes (`id`, `classification`, `classification_sort_key`), so
+    /// this collapses each one down to a plain string before JSON- and
+    /// base64-encoding the map, rather than serializing the full
+    /// `AttributeValue` enum. Returns `None` if `key` somehow contains a
+    /// non-string attribute, which should never happen for this GSI.
+    pub fn encode_cursor(key: &HashMap<String, AttributeValue>) -> Option<String> {
+        let plain: HashMap<String, String> = key.iter()
+            .map(|(k, v)| Some((k.clone(), v.as_s().ok()?.clone())))
+            .collect::<Option<_>>()?;
+
+        let json = serde_json::to_string(&plain).ok()?;
+        Some(base64::engine::general_purpose::STANDARD.encode(json))
+    }
+
+    /// Reverses [`encode_cursor`], rejecting anything that isn't base64-encoded
+    /// JSON mapping attribute names to strings as `AppError::Validation`.
+    pub fn decode_cursor(cursor: &str) -> Result<HashMap<String, AttributeValue>, AppError> {
+        let json = base64::engine::general_purpose::STANDARD
+            .decode(cursor)
+            .map_err(|_| AppError::Validation("Invalid pagination cursor".to_string()))?;
+
+        let plain: HashMap<String, String> = serde_json::from_slice(&json)
+            .map_err(|_| AppError::Validation("Invalid pagination cursor".to_string()))?;
+
+        Ok(plain.into_iter().map(|(k, v)| (k, AttributeValue::S(v))).collect())
+    }
+
+    /// Attempts to claim an event ID for processing so redelivered SQS
+    /// messages aren't processed twice.
+    ///
+    /// Performs a conditional `put_item` against the dedup table that only
+    /// succeeds if `event_id` hasn't been claimed before, surfacing the
+    /// conditional-check failure as `AppError::Duplicate` — the same pattern
+    /// every other conditional write in this repository uses to map its
+    /// check failure onto a specific `AppError` variant, rather than folding
+    /// it into a plain boolean the caller has to know to interpret. The
+    /// claim row carries a `ttl` attribute (DynamoDB TTL, in epoch seconds)
+    /// so claims expire automatically after `ttl_secs`.
+    pub async fn try_claim_event(&self, event_id: &str, ttl_secs: i64) -> Result<(), AppError> {
+        let now = chrono::Utc::now();
+        let mut claim_attributes = HashMap::new();
+        claim_attributes.insert("id".to_string(), AttributeValue::S(event_id.to_string()));
+        claim_attributes.insert("claimed_at".to_string(), AttributeValue::S(now.to_rfc3339()));
+        claim_attributes.insert(
+            "ttl".to_string(),
+            AttributeValue::N((now.timestamp() + ttl_secs).to_string()),
+        );
+
+        self.client
+            .put_item()
+            .table_name(&self.dedup_table_name)
+            .set_item(Some(claim_attributes))
+            .condition_expression("attribute_not_exists(id)")
+            .send()
+            .await
+            .map_err(|e| match Error::from(e) {
+                Error::ConditionalCheckFailedException(_) => {
+                    AppError::Duplicate(format!("Event {} has already been claimed", event_id))
+                },
+                other => AppError::DynamoDb(other),
+            })?;
+
+        Ok(())
+    }
+
+    /// Records `attachment` against the item with ID `item_id` via a
+    /// conditional `update_item` that atomically appends to the stored
+    /// `attachments` list server-side, rather than a read-modify-write
+    /// full-item overwrite.
+    ///
+    /// A read-then-write overwrite loses data under concurrent uploads: two
+    /// `add_attachment` calls for the same item can both read the item
+    /// before either writes it back, so whichever `put_item` lands second
+    /// silently clobbers the attachment the first one added. `list_append`
+    /// is applied by DynamoDB itself against whatever the current list is at
+    /// write time, so concurrent uploads to the same item both land.
+    pub async fn add_attachment(&self, item_id: &str, attachment: crate::models::Attachment) -> Result<Item, AppError> {
+        let response = self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key("id", AttributeValue::S(item_id.to_string()))
+            .update_expression("SET attachments = list_append(if_not_exists(attachments, :empty), :new)")
+            .condition_expression("attribute_exists(id)")
+            .expression_attribute_values(":empty", AttributeValue::L(Vec::new()))
+            .expression_attribute_values(":new", AttributeValue::L(vec![Self::attachment_attributes(&attachment)]))
+            .return_values(aws_sdk_dynamodb::model::ReturnValue::AllNew)
+            .send()
+            .await
+            .map_err(|e| match Error::from(e) {
+                Error::ConditionalCheckFailedException(_) => {
+                    AppError::NotFound(format!("Item with ID {} not found", item_id))
+                },
+                other => AppError::DynamoDb(other),
+            })?;
+
+        response.attributes()
+            .and_then(Self::item_from_attributes)
+            .ok_or_else(|| AppError::Internal(format!(
+                "update_item for item {} did not return its updated attributes", item_id
+            )))
+    }
+
+    /// Returns the most recent audit record for `resource_id`, if any
+    ///
+    /// Used to look up `prev_hash` when appending the next link in the
+    /// resource's tamper-evident audit chain.
+    pub async fn latest_audit_record(&self, resource_id: &str) -> Result<Option<AuditRecord>, Error> {
+        let response = self.client
+            .query()
+            .table_name(&self.audit_table_name)
+            .key_condition_expression("resource_id = :resource_id")
+            .expression_attribute_values(":resource_id", AttributeValue::S(resource_id.to_string()))
+            .scan_index_forward(false)
+            .limit(1)
+            .send()
+            .await?;
+
+        Ok(response.items().unwrap_or_default().first().and_then(Self::audit_from_attributes))
+    }
+
+    /// Returns the full audit chain for `resource_id`, oldest first
+    ///
+    /// Used by the API handler's `GET /audit/{resource_id}` endpoint, which
+    /// passes the records to `verify_chain` to walk and validate the chain
+    /// from the beginning.
+    pub async fn get_audit_chain(&self, resource_id: &str) -> Result<Vec<AuditRecord>, Error> {
+        let response = self.client
+            .query()
+            .table_name(&self.audit_table_name)
+            .key_condition_expression("resource_id = :resource_id")
+            .expression_attribute_values(":resource_id", AttributeValue::S(resource_id.to_string()))
+            .scan_index_forward(true)
+            .send()
+            .await?;
+
+        let items = response.items().unwrap_or_default();
+
+        Ok(items.iter().filter_map(Self::audit_from_attributes).collect())
+    }
+
     pub async fn delete_item(&self, id: &str) -> Result<(), Error> {
         info!("Deleting item with ID: {}", id);
-        
+
         self.client
             .delete_item()
             .table_name(&self.table_name)
             .key("id", AttributeValue::S(id.to_string()))
             .send()
             .await?;
-            
+
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Attachment;
+
+    fn sample_item() -> Item {
+        Item {
+            id: "test-id".to_string(),
+            name: "Test Item".to_string(),
+            description: Some("Test Description".to_string()),
+            created_at: chrono::DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&chrono::Utc),
+            classification: "CONFIDENTIAL".to_string(),
+            attachments: vec![Attachment {
+                name: "report.pdf".to_string(),
+                s3_key: "attachments/test-id/report.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                size_bytes: 1024,
+                sha256: "deadbeef".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn item_attributes_round_trips_through_item_from_attributes() {
+        let item = sample_item();
+        let attributes = DynamoDbRepository::item_attributes(&item);
+        let round_tripped = DynamoDbRepository::item_from_attributes(&attributes).unwrap();
+
+        assert_eq!(round_tripped.id, item.id);
+        assert_eq!(round_tripped.name, item.name);
+        assert_eq!(round_tripped.description, item.description);
+        assert_eq!(round_tripped.created_at, item.created_at);
+        assert_eq!(round_tripped.classification, item.classification);
+        assert_eq!(round_tripped.attachments.len(), item.attachments.len());
+        assert_eq!(round_tripped.attachments[0].name, item.attachments[0].name);
+    }
+
+    #[test]
+    fn item_attributes_includes_classification_sort_key_for_gsi() {
+        let item = sample_item();
+        let attributes = DynamoDbRepository::item_attributes(&item);
+
+        let sort_key = attributes.get("classification_sort_key").unwrap().as_s().unwrap();
+        assert_eq!(sort_key, &format!("{}#{}", item.created_at.to_rfc3339(), item.id));
+    }
+
+    #[test]
+    fn item_from_attributes_defaults_missing_fields() {
+        let mut attributes = HashMap::new();
+        attributes.insert("id".to_string(), AttributeValue::S("test-id".to_string()));
+        attributes.insert("name".to_string(), AttributeValue::S("Test Item".to_string()));
+
+        let item = DynamoDbRepository::item_from_attributes(&attributes).unwrap();
+
+        assert_eq!(item.description, None);
+        assert_eq!(item.classification, "INTERNAL");
+        assert!(item.attachments.is_empty());
+    }
+
+    #[test]
+    fn audit_attributes_round_trips_through_audit_from_attributes() {
+        let audit = AuditRecord {
+            event_id: "event-1".to_string(),
+            user_id: "alice".to_string(),
+            action: "create".to_string(),
+            resource_id: "test-id".to_string(),
+            resource_type: "item".to_string(),
+            timestamp: chrono::Utc::now(),
+            previous_state: None,
+            new_state: Some("{}".to_string()),
+            request_id: "req-1".to_string(),
+            prev_hash: None,
+            hash: Some("abc123".to_string()),
+        };
+
+        let attributes = DynamoDbRepository::audit_attributes(&audit);
+        let round_tripped = DynamoDbRepository::audit_from_attributes(&attributes).unwrap();
+
+        assert_eq!(round_tripped.event_id, audit.event_id);
+        assert_eq!(round_tripped.action, audit.action);
+        assert_eq!(round_tripped.resource_id, audit.resource_id);
+        assert_eq!(round_tripped.hash, audit.hash);
+        assert_eq!(round_tripped.prev_hash, audit.prev_hash);
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,81 @@
+use std::future::Future;
+use std::time::Duration;
+use rand::Rng;
+use tracing::warn;
+use crate::error::AppError;
+
+/// Configuration for retrying throttled DynamoDB calls with exponential
+/// backoff and full jitter
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoffConfig {
+    /// Upper bound on the (jittered) delay before the first retry, doubled
+    /// for each retry after that
+    pub base_delay: Duration,
+
+    /// Upper bound on the computed delay, regardless of attempt count
+    pub max_delay: Duration,
+
+    /// Total number of attempts (including the first), after which the
+    /// last error is returned instead of retrying again
+    pub max_attempts: u32,
+}
+
+impl Default for ExponentialBackoffConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(5),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ExponentialBackoffConfig {
+    /// Computes the delay before the given (zero-indexed) retry attempt as
+    /// `random(0, min(max_delay, base_delay * 2^attempt))`
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.as_millis().saturating_mul(1u128 << attempt.min(32));
+        let capped = exponential.min(self.max_delay.as_millis()).max(1);
+
+        Duration::from_millis(rand::thread_rng().gen_range(0..=capped) as u64)
+    }
+}
+
+/// Runs `operation`, retrying with exponential backoff and full jitter when
+/// it fails with a DynamoDB throttling error, up to `config.max_attempts`
+/// total tries.
+///
+/// Any non-throttling error is returned immediately without retrying.
+pub async fn retry_on_throttle<F, Fut, T>(
+    config: &ExponentialBackoffConfig,
+    mut operation: F,
+) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt + 1 < config.max_attempts && is_throttling_error(&err) => {
+                let delay = config.delay_for_attempt(attempt);
+                warn!("Retrying after throttling error (attempt {}): {}", attempt + 1, err);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            },
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Returns whether `err` represents a DynamoDB throttling condition that is
+/// safe to retry
+fn is_throttling_error(err: &AppError) -> bool {
+    matches!(
+        err,
+        AppError::DynamoDb(aws_sdk_dynamodb::Error::ProvisionedThroughputExceededException(_))
+            | AppError::DynamoDb(aws_sdk_dynamodb::Error::RequestLimitExceeded(_))
+    )
+}
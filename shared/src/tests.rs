@@ -12,6 +12,8 @@ mod tests {
             created_at: DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
                 .unwrap()
                 .with_timezone(&Utc),
+            classification: "INTERNAL".to_string(),
+            attachments: Vec::new(),
         };
 
         let serialized = serde_json::to_string(&item).unwrap();
@@ -21,6 +23,7 @@ mod tests {
         assert_eq!(item.name, deserialized.name);
         assert_eq!(item.description, deserialized.description);
         assert_eq!(item.created_at, deserialized.created_at);
+        assert_eq!(item.classification, deserialized.classification);
     }
 
     #[test]
@@ -32,6 +35,8 @@ mod tests {
             created_at: DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
                 .unwrap()
                 .with_timezone(&Utc),
+            classification: "INTERNAL".to_string(),
+            attachments: Vec::new(),
         };
 
         let event = ItemEvent {
@@ -59,6 +64,7 @@ mod tests {
             ItemEventType::Created,
             ItemEventType::Updated,
             ItemEventType::Deleted,
+            ItemEventType::AttachmentAdded,
         ];
 
         for event_type in event_types {